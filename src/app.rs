@@ -1,35 +1,123 @@
 use anyhow::{anyhow, Result};
-use inquire::Text;
-use std::path::PathBuf;
+use clap::ValueEnum;
+use inquire::{Confirm, Text};
+use std::cell::RefCell;
+use std::fmt;
+use std::path::{Path, PathBuf};
 
-use crate::cli_engine::CliEngine;
-use crate::config::ProjectConfig;
-use crate::opts::{Commands, Opts};
+use crate::cli_engine::{interactive_init, CliEngine};
+use crate::config::{ConflictPolicy, OutputFormat, Preferences, ProjectConfig};
+use crate::doctor::{report, Finding};
+use crate::migrate::{migrate_file, CURRENT_SCHEMA_VERSION};
+use crate::opts::{Commands, ConfigAction, Opts};
+use crate::user_config::{
+    user_config_dir, user_config_dir_variable, UserConfig, USER_CONFIG_FILE, USER_TEMPLATES_DIR,
+};
+use crate::watch::{watch, watched_files};
+use crate::wizard::{confirm_overwrite, run_wizard};
+use crate::workspace::{find_workspace_file, WorkspaceConfig};
 
 #[derive(Debug)]
 pub struct Config {
     pub commands: Commands,
     pub config_path: PathBuf,
     pub source_dir: PathBuf,
+    /// Selected project when `config_path` is a workspace file
+    pub project: Option<String>,
+    /// Where the config path, source dir and project were taken from
+    pub provenance: Provenance,
+}
+
+/// Where the settings resolved before loading the config came from
+#[derive(Debug, Clone, Default)]
+pub struct Provenance {
+    pub config_path: ValueSource,
+    pub source_dir: ValueSource,
+    pub project: Option<ValueSource>,
+}
+
+/// Origin of a resolved setting, as reported by `config show`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ValueSource {
+    /// Command-line flag
+    Flag(&'static str),
+    /// Environment variable
+    Env(&'static str),
+    /// Config file that sets the value
+    File(PathBuf),
+    /// Found by looking around the current directory
+    Detected,
+    /// Entered at a prompt
+    Prompt,
+    /// Built-in default
+    #[default]
+    Default,
+}
+
+impl fmt::Display for ValueSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueSource::Flag(flag) => write!(f, "flag {}", flag),
+            ValueSource::Env(variable) => write!(f, "env ${}", variable),
+            ValueSource::File(path) => write!(f, "file {}", path.display()),
+            ValueSource::Detected => write!(f, "detected"),
+            ValueSource::Prompt => write!(f, "prompt"),
+            ValueSource::Default => write!(f, "default"),
+        }
+    }
 }
 
 impl TryFrom<Opts> for Config {
     type Error = anyhow::Error;
 
     fn try_from(value: Opts) -> Result<Self> {
-        let config_path = get_config_path(value.config)?;
-        let source_dir = get_source_dir(value.source_dir)?;
-        let commands = get_commands(value.commands, &config_path)?;
+        let (config_path, config_source) = get_config_path(value.config)?;
+
+        let (source_dir, source_dir_source, project, project_source) =
+            if WorkspaceConfig::is_workspace_file(&config_path) {
+                let (project, project_source, project_source_dir) =
+                    select_workspace_project(&config_path, value.project)?;
+                let (source_dir, source_dir_source) = match value.source_dir {
+                    Some(source_dir) => (source_dir, ValueSource::Flag("--source_dir")),
+                    None => (project_source_dir, ValueSource::File(config_path.clone())),
+                };
+                (
+                    source_dir,
+                    source_dir_source,
+                    Some(project),
+                    Some(project_source),
+                )
+            } else if value.project.is_some() {
+                return Err(anyhow!(
+                    "--project requires a workspace config, but {} describes a single project",
+                    config_path.display()
+                ));
+            } else {
+                let (source_dir, source_dir_source) = get_source_dir(value.source_dir)?;
+                (source_dir, source_dir_source, None, None)
+            };
+
+        let commands = get_commands(value.commands, &config_path, project.as_deref())?;
 
         Ok(Config {
             commands,
             config_path,
             source_dir,
+            project,
+            provenance: Provenance {
+                config_path: config_source,
+                source_dir: source_dir_source,
+                project: project_source,
+            },
         })
     }
 }
 
-fn get_commands(commands: Option<Commands>, config_path: &PathBuf) -> Result<Commands> {
+fn get_commands(
+    commands: Option<Commands>,
+    config_path: &Path,
+    project: Option<&str>,
+) -> Result<Commands> {
     if let Some(c) = commands {
         // Commands provided via CLI - validate config for all commands except Interactive
         match &c {
@@ -37,16 +125,23 @@ fn get_commands(commands: Option<Commands>, config_path: &PathBuf) -> Result<Com
                 // Interactive mode - validate config later when running
                 return Ok(c);
             }
+            Commands::Render { watch: true, .. } => {
+                // Watch mode reports config errors on every change instead of exiting
+                return Ok(c);
+            }
+            Commands::Doctor => {
+                // Doctor reports an invalid config as one of its findings
+                return Ok(c);
+            }
             _ => {
                 // Other commands - validate config early
-                let _project_config =
-                    ProjectConfig::load_and_validate(config_path).map_err(|e| {
-                        anyhow!(
-                            "Config validation failed: {}. Please fix {} and try again.",
-                            e,
-                            config_path.display()
-                        )
-                    })?;
+                let _project_config = load_project_config(config_path, project).map_err(|e| {
+                    anyhow!(
+                        "Config validation failed: {}\n💡 Please fix {} and try again.",
+                        e,
+                        config_path.display()
+                    )
+                })?;
             }
         }
 
@@ -60,24 +155,31 @@ fn get_commands(commands: Option<Commands>, config_path: &PathBuf) -> Result<Com
     ))
 }
 
-fn get_config_path(config: Option<PathBuf>) -> Result<PathBuf> {
+/// Config files looked up in the current directory, in order
+const DEFAULT_CONFIG_FILES: [&str; 3] = [
+    "config.json",
+    "config-clean-architecture.json",
+    "config-module-based.json",
+];
+
+/// Source directories looked up in the current directory, in order
+const COMMON_SOURCE_DIRS: [&str; 3] = ["src", "app", "lib"];
+
+fn get_config_path(config: Option<PathBuf>) -> Result<(PathBuf, ValueSource)> {
     if let Some(c) = config {
-        return Ok(c);
+        return Ok((c, ValueSource::Flag("--config")));
     }
 
     // Try to find config automatically
-    let default_configs = [
-        "config.json",
-        "config-clean-architecture.json",
-        "config-module-based.json",
-    ];
-
-    for config_name in &default_configs {
-        let path = PathBuf::from(config_name);
-        if path.exists() {
-            println!("📋 Found config: {}", config_name);
-            return Ok(path);
-        }
+    if let Some(path) = find_default_config() {
+        eprintln!("📋 Found config: {}", path.display());
+        return Ok((path, ValueSource::Detected));
+    }
+
+    // Then look for a workspace in the current directory and its parents
+    if let Some(path) = find_workspace_from_current() {
+        eprintln!("📋 Found workspace: {}", path.display());
+        return Ok((path, ValueSource::Detected));
     }
 
     // If no config found, ask user
@@ -86,27 +188,123 @@ fn get_config_path(config: Option<PathBuf>) -> Result<PathBuf> {
         .prompt()
         .map_err(|_| anyhow!("Failed to read the config file path."))?;
 
-    Ok(PathBuf::from(&config_path))
+    Ok((PathBuf::from(&config_path), ValueSource::Prompt))
+}
+
+fn find_default_config() -> Option<PathBuf> {
+    DEFAULT_CONFIG_FILES
+        .iter()
+        .map(PathBuf::from)
+        .find(|path| path.exists())
+}
+
+fn find_workspace_from_current() -> Option<PathBuf> {
+    std::env::current_dir()
+        .ok()
+        .and_then(|dir| find_workspace_file(&dir))
+}
+
+fn find_common_source_dir() -> Option<PathBuf> {
+    COMMON_SOURCE_DIRS
+        .iter()
+        .map(PathBuf::from)
+        .find(|path| path.is_dir())
+}
+
+/// Load the project config and source dir the way commands do, but without
+/// printing or prompting. Used by shell completion, which gives up on any problem.
+pub fn load_quietly(opts: &Opts) -> Option<(ProjectConfig, PathBuf)> {
+    let config_path = opts
+        .config
+        .clone()
+        .or_else(find_default_config)
+        .or_else(find_workspace_from_current)?;
+
+    if WorkspaceConfig::is_workspace_file(&config_path) {
+        let (project, _, project_source_dir) =
+            find_workspace_project(&config_path, opts.project.clone()).ok()?;
+        let project_config = load_project_config(&config_path, Some(&project)).ok()?;
+        let source_dir = opts.source_dir.clone().unwrap_or(project_source_dir);
+        return Some((project_config, source_dir));
+    }
+
+    let source_dir = opts.source_dir.clone().or_else(find_common_source_dir)?;
+    Some((load_project_config(&config_path, None).ok()?, source_dir))
+}
+
+/// Pick the workspace project from `--project` or the current directory.
+/// Returns the project name, how it was chosen and its source directory.
+fn select_workspace_project(
+    workspace_path: &Path,
+    project: Option<String>,
+) -> Result<(String, ValueSource, PathBuf)> {
+    let selected = find_workspace_project(workspace_path, project)?;
+    eprintln!("📦 Using project: {}", selected.0);
+    Ok(selected)
+}
+
+fn find_workspace_project(
+    workspace_path: &Path,
+    project: Option<String>,
+) -> Result<(String, ValueSource, PathBuf)> {
+    let workspace = WorkspaceConfig::load_and_validate(workspace_path)?;
+    let workspace_dir = workspace_dir(workspace_path);
+
+    let (selected, source) = match project {
+        Some(name) => (
+            workspace.find_project(&name)?,
+            ValueSource::Flag("--project"),
+        ),
+        None => {
+            let cwd = std::env::current_dir()
+                .map_err(|e| anyhow!("Failed to read the current directory: {}", e))?;
+            let detected = workspace.detect_project(workspace_dir, &cwd).ok_or_else(|| {
+                anyhow!(
+                    "Could not detect the workspace project from the current directory.\n💡 Run inside a project root or pass --project <name> (available: {})",
+                    workspace.project_names().join(", ")
+                )
+            })?;
+            (detected, ValueSource::Detected)
+        }
+    };
+
+    Ok((
+        selected.name.clone(),
+        source,
+        selected.source_dir(workspace_dir),
+    ))
+}
+
+/// Directory that workspace paths are relative to
+fn workspace_dir(workspace_path: &Path) -> &Path {
+    workspace_path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."))
+}
+
+/// Load the project config, from the workspace when a project is selected
+fn load_project_config(config_path: &Path, project: Option<&str>) -> Result<ProjectConfig> {
+    match project {
+        Some(project) => WorkspaceConfig::load_and_validate(config_path)?
+            .load_project_config(workspace_dir(config_path), project),
+        None => ProjectConfig::load_and_validate(config_path),
+    }
 }
 
-fn get_source_dir(source_dir: Option<PathBuf>) -> Result<PathBuf> {
+fn get_source_dir(source_dir: Option<PathBuf>) -> Result<(PathBuf, ValueSource)> {
     if let Some(s) = source_dir {
-        return Ok(s);
+        return Ok((s, ValueSource::Flag("--source_dir")));
     }
 
     get_source_dir_from_current()
 }
 
-fn get_source_dir_from_current() -> Result<PathBuf> {
+fn get_source_dir_from_current() -> Result<(PathBuf, ValueSource)> {
     // Try common source directories
-    let common_dirs = ["src", "app", "lib"];
-
-    for dir_name in &common_dirs {
-        let path = PathBuf::from(dir_name);
-        if path.exists() && path.is_dir() {
-            println!("📁 Found source directory: {}", dir_name);
-            return Ok(path);
-        }
+    if let Some(path) = find_common_source_dir() {
+        eprintln!("📁 Found source directory: {}", path.display());
+        return Ok((path, ValueSource::Detected));
     }
 
     // If no common directory found, ask user
@@ -115,14 +313,55 @@ fn get_source_dir_from_current() -> Result<PathBuf> {
         .prompt()
         .map_err(|_| anyhow!("Failed to read the source directory path."))?;
 
-    Ok(PathBuf::from(&source_path))
+    Ok((PathBuf::from(&source_path), ValueSource::Prompt))
 }
 
 /// Execute the loaded configuration
 pub fn execute_config(config: Config) -> Result<()> {
+    if let Commands::Config {
+        action: ConfigAction::Migrate { dry_run },
+    } = &config.commands
+    {
+        return handle_config_migrate(&config, *dry_run);
+    }
+
+    if let Commands::Render { watch: true, .. } = &config.commands {
+        handle_render_watch(&config);
+    }
+
+    // Neither needs a loadable config: init replaces it and interactive offers to
+    match &config.commands {
+        Commands::Init { preset, wizard } => {
+            return execute_init(preset.as_deref(), *wizard, &config.config_path);
+        }
+        Commands::Interactive => return handle_interactive(config),
+        Commands::Doctor => return handle_doctor(&config),
+        _ => {}
+    }
+
     // Load project config again for execution
-    let project_config = ProjectConfig::load_and_validate(&config.config_path)?;
-    let cli_engine = CliEngine::new(project_config, config.source_dir);
+    let mut project_config = load_project_config(&config.config_path, config.project.as_deref())?;
+
+    let user_dir = user_config_dir();
+    let user_config = match &user_dir {
+        Some(dir) => UserConfig::load(dir)?,
+        None => UserConfig::default(),
+    };
+
+    if let Commands::Config {
+        action: ConfigAction::Show,
+    } = &config.commands
+    {
+        return handle_config_show(&config, &project_config, &user_config, user_dir.as_deref());
+    }
+
+    // Precedence: command flags > project config > user config > built-in defaults
+    if let Some(dir) = &user_dir {
+        user_config.merge_under(&mut project_config, dir);
+    }
+    apply_command_preferences(&mut project_config, &config.commands);
+
+    let cli_engine = CliEngine::new(project_config, config.source_dir.clone());
 
     // Execute the command
     match &config.commands {
@@ -132,19 +371,372 @@ pub fn execute_config(config: Config) -> Result<()> {
         Commands::List { .. } => {
             cli_engine.handle_list(config.commands)?;
         }
-        Commands::Init { preset } => {
-            handle_init(preset.as_deref(), &config.config_path)?;
+        Commands::Render { .. } => {
+            cli_engine.handle_render(config.commands)?;
+        }
+        Commands::TestTemplates { update } => {
+            cli_engine.handle_test_templates(*update)?;
+        }
+        Commands::Module { .. } => {
+            cli_engine.handle_module(config.commands)?;
+        }
+        Commands::Init { .. } | Commands::Interactive | Commands::Doctor => {
+            unreachable!("init, interactive mode and doctor are handled above")
         }
-        Commands::Interactive => {
-            cli_engine.handle_interactive()?;
+        Commands::Config { .. } => unreachable!("config commands are handled above"),
+        Commands::Completions { .. } | Commands::Complete { .. } => {
+            unreachable!("completion commands are handled before loading the config")
         }
     }
 
     Ok(())
 }
 
-/// Handle init command (create new config)
-fn handle_init(preset: Option<&str>, config_path: &PathBuf) -> Result<()> {
+/// Run interactive mode. The config is loaded here rather than up front, so that a
+/// missing or broken config can be replaced with a new one.
+fn handle_interactive(config: Config) -> Result<()> {
+    let command = match load_effective_config(&config) {
+        Ok(project_config) => {
+            CliEngine::new(project_config, config.source_dir.clone()).handle_interactive()?
+        }
+        Err(e) => {
+            eprintln!("⚠️  Could not load {}: {}", config.config_path.display(), e);
+            offer_init(&config.config_path)?
+        }
+    };
+
+    // Init and config commands chosen interactively run like their CLI versions
+    let Some(command) = command else {
+        return Ok(());
+    };
+    if let Commands::Init { wizard: false, .. } = &command {
+        if config.config_path.exists() && !confirm_overwrite(&config.config_path)? {
+            println!("Kept {}", config.config_path.display());
+            return Ok(());
+        }
+    }
+    execute_config(Config {
+        commands: command,
+        ..config
+    })
+}
+
+/// Handle `doctor`: check the config, then the project it describes
+fn handle_doctor(config: &Config) -> Result<()> {
+    let findings = match load_effective_config(config) {
+        Ok(project_config) => {
+            let mut findings = vec![Finding::ok(format!(
+                "Config {} is valid",
+                config.config_path.display()
+            ))];
+            findings.extend(CliEngine::new(project_config, config.source_dir.clone()).doctor());
+            findings
+        }
+        Err(e) => vec![Finding::problem(format!(
+            "Config {} doesn't load: {}",
+            config.config_path.display(),
+            e
+        ))],
+    };
+    report(&findings)
+}
+
+/// Offer to create a config when interactive mode can't load one
+fn offer_init(config_path: &Path) -> Result<Option<Commands>> {
+    let init = Confirm::new(&format!(
+        "Initialize a new config at {}?",
+        config_path.display()
+    ))
+    .with_default(true)
+    .prompt()
+    .map_err(|_| anyhow!("Failed to confirm initializing a new config"))?;
+
+    if init {
+        interactive_init().map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Render on every change to the config or templates, reporting errors inline.
+/// The config is loaded and validated again each time. Runs until interrupted.
+fn handle_render_watch(config: &Config) -> ! {
+    let last_config = RefCell::new(None);
+    println!(
+        "👀 Watching {} and its templates. Press Ctrl+C to stop.",
+        config.config_path.display()
+    );
+
+    watch(
+        || watched_files(&config.config_path, last_config.borrow().as_ref()),
+        || {
+            println!();
+            println!("🔄 Rendering...");
+            let project_config = match load_effective_config(config) {
+                Ok(project_config) => project_config,
+                Err(e) => {
+                    eprintln!("❌ Config error: {}", e);
+                    return;
+                }
+            };
+            last_config.replace(Some(project_config.clone()));
+
+            let cli_engine = CliEngine::new(project_config, config.source_dir.clone());
+            if let Err(e) = cli_engine.handle_render(config.commands.clone()) {
+                eprintln!("❌ Render error: {}", e);
+            }
+        },
+    )
+}
+
+/// Load the project config with the user config and command flags merged in
+fn load_effective_config(config: &Config) -> Result<ProjectConfig> {
+    let mut project_config = load_project_config(&config.config_path, config.project.as_deref())?;
+    if let Some(dir) = user_config_dir() {
+        UserConfig::load(&dir)?.merge_under(&mut project_config, &dir);
+    }
+    apply_command_preferences(&mut project_config, &config.commands);
+    Ok(project_config)
+}
+
+/// Let command flags override the configured preferences
+fn apply_command_preferences(project_config: &mut ProjectConfig, commands: &Commands) {
+    if let Commands::Create {
+        on_conflict,
+        output,
+        ..
+    } = commands
+    {
+        let flags = Preferences {
+            on_conflict: *on_conflict,
+            output: *output,
+            editor: None,
+        };
+        project_config.preferences = Some(flags.or(project_config.preferences()));
+    }
+}
+
+/// Handle `config show`: print the fully resolved settings and where each one comes from
+fn handle_config_show(
+    config: &Config,
+    project_config: &ProjectConfig,
+    user_config: &UserConfig,
+    user_dir: Option<&Path>,
+) -> Result<()> {
+    let provenance = &config.provenance;
+    let user_dir_source = user_config_dir_variable()
+        .map(ValueSource::Env)
+        .unwrap_or_default();
+
+    println!("⚙️  Effective configuration");
+    println!(
+        "   Config file: {} ({})",
+        config.config_path.display(),
+        provenance.config_path
+    );
+    if let (Some(project), Some(source)) = (&config.project, &provenance.project) {
+        println!("   Workspace project: {} ({})", project, source);
+    }
+    println!(
+        "   Source dir: {} ({})",
+        config.source_dir.display(),
+        provenance.source_dir
+    );
+    match project_config.templates_dir() {
+        // Inline workspace projects take it from the workspace file
+        Some(dir) => println!(
+            "   Templates dir: {} ({})",
+            dir.display(),
+            ValueSource::File(
+                project_config
+                    .templates_dir_source
+                    .clone()
+                    .unwrap_or_else(|| config.config_path.clone())
+            )
+        ),
+        None => println!(
+            "   Templates dir: none, template paths are used as written ({})",
+            ValueSource::Default
+        ),
+    }
+
+    match user_dir {
+        Some(dir) => {
+            let found = |path: &Path| if path.exists() { "found" } else { "not found" };
+            let user_file = dir.join(USER_CONFIG_FILE);
+            let templates = dir.join(USER_TEMPLATES_DIR);
+            println!(
+                "   User config: {} ({}, {})",
+                user_file.display(),
+                found(&user_file),
+                user_dir_source
+            );
+            println!(
+                "   Template overrides: {} ({}, {})",
+                templates.display(),
+                found(&templates),
+                user_dir_source
+            );
+        }
+        None => println!("   User config: unavailable (neither $XDG_CONFIG_HOME nor $HOME is set)"),
+    }
+
+    println!();
+    println!("🎛️  Preferences (precedence: command flag > project config > user config > default)");
+
+    let project_file = ValueSource::File(config.config_path.clone());
+    let user_file = user_dir
+        .map(|dir| ValueSource::File(dir.join(USER_CONFIG_FILE)))
+        .unwrap_or_default();
+    let preferences = resolve_preferences(
+        &project_config.preferences(),
+        &user_config.preferences,
+        &project_file,
+        &user_file,
+    );
+
+    let (on_conflict, source) = &preferences.on_conflict;
+    println!("   on_conflict: {} ({})", value_name(*on_conflict), source);
+    let (output, source) = &preferences.output;
+    println!("   output: {} ({})", value_name(*output), source);
+    let (editor, source) = &preferences.editor;
+    println!(
+        "   editor: {} ({})",
+        editor.as_deref().unwrap_or("none"),
+        source
+    );
+
+    // The merged config as `create` sees it, with every default filled in
+    let mut resolved = project_config.clone();
+    resolved.preferences = Some(Preferences {
+        on_conflict: Some(preferences.on_conflict.0),
+        output: Some(preferences.output.0),
+        editor: preferences.editor.0,
+    });
+    let json = serde_json::to_string_pretty(&resolved)
+        .map_err(|e| anyhow!("Failed to serialize the resolved config: {}", e))?;
+
+    println!();
+    println!("📄 Resolved config");
+    println!("{}", json);
+
+    Ok(())
+}
+
+/// Preferences with built-in defaults applied, each paired with its origin
+struct ResolvedPreferences {
+    on_conflict: (ConflictPolicy, ValueSource),
+    output: (OutputFormat, ValueSource),
+    editor: (Option<String>, ValueSource),
+}
+
+fn resolve_preferences(
+    project: &Preferences,
+    user: &Preferences,
+    project_file: &ValueSource,
+    user_file: &ValueSource,
+) -> ResolvedPreferences {
+    let pick = |from_project: bool, from_user: bool| {
+        if from_project {
+            project_file.clone()
+        } else if from_user {
+            user_file.clone()
+        } else {
+            ValueSource::Default
+        }
+    };
+
+    let editor = match project.editor.clone().or_else(|| user.editor.clone()) {
+        Some(editor) => (
+            Some(editor),
+            pick(project.editor.is_some(), user.editor.is_some()),
+        ),
+        None => ["VISUAL", "EDITOR"]
+            .into_iter()
+            .find_map(|name| {
+                std::env::var(name)
+                    .ok()
+                    .map(|value| (Some(value), ValueSource::Env(name)))
+            })
+            .unwrap_or((None, ValueSource::Default)),
+    };
+
+    ResolvedPreferences {
+        on_conflict: (
+            project.on_conflict.or(user.on_conflict).unwrap_or_default(),
+            pick(project.on_conflict.is_some(), user.on_conflict.is_some()),
+        ),
+        output: (
+            project.output.or(user.output).unwrap_or_default(),
+            pick(project.output.is_some(), user.output.is_some()),
+        ),
+        editor,
+    }
+}
+
+/// Handle `config migrate`: upgrade the project's config file to the current schema
+fn handle_config_migrate(config: &Config, dry_run: bool) -> Result<()> {
+    let config_file = match &config.project {
+        Some(name) => {
+            let workspace = WorkspaceConfig::load_and_validate(&config.config_path)?;
+            let project = workspace.find_project(name)?;
+            let config_file = project.config.as_ref().ok_or_else(|| {
+                anyhow!(
+                    "Project '{}' is declared inline in the workspace file, which has no schema to migrate",
+                    name
+                )
+            })?;
+            workspace_dir(&config.config_path).join(config_file)
+        }
+        None => config.config_path.clone(),
+    };
+
+    let changes = migrate_file(&config_file, dry_run)?;
+    if changes.is_empty() {
+        println!(
+            "✅ {} is already at schema_version {}",
+            config_file.display(),
+            CURRENT_SCHEMA_VERSION
+        );
+        return Ok(());
+    }
+
+    println!("🔄 Changes to {}:", config_file.display());
+    for change in &changes {
+        println!("   {}", change);
+    }
+
+    if dry_run {
+        println!("🔍 Dry run: nothing was written");
+    } else {
+        println!(
+            "✅ Migrated {} to schema_version {}",
+            config_file.display(),
+            CURRENT_SCHEMA_VERSION
+        );
+    }
+
+    Ok(())
+}
+
+/// Name of an enum value as written in config files and flags
+fn value_name<T: ValueEnum>(value: T) -> String {
+    value
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+/// Run `creator init`, which needs no existing config: copy a preset or run the wizard
+pub fn execute_init(preset: Option<&str>, wizard: bool, config_path: &Path) -> Result<()> {
+    if wizard {
+        run_wizard(config_path)
+    } else {
+        handle_init(preset, config_path)
+    }
+}
+
+fn handle_init(preset: Option<&str>, config_path: &Path) -> Result<()> {
     println!("🚀 Initializing new Creator project...");
 
     let template_config = match preset {
@@ -175,7 +767,7 @@ fn handle_init(preset: Option<&str>, config_path: &PathBuf) -> Result<()> {
     let target_config = if config_path.file_name().unwrap() == "config.json" {
         PathBuf::from("config.json")
     } else {
-        config_path.clone()
+        config_path.to_path_buf()
     };
 
     std::fs::write(&target_config, template_config)
@@ -197,6 +789,43 @@ mod tests {
         // For now, just test the manual path input
         let manual_path = get_config_path(Some(PathBuf::from("test-config.json")));
         assert!(manual_path.is_ok());
+        assert_eq!(manual_path.unwrap().1, ValueSource::Flag("--config"));
+    }
+
+    #[test]
+    fn test_resolve_preferences_provenance() {
+        let project = Preferences {
+            output: Some(OutputFormat::Json),
+            editor: Some("vim".to_string()),
+            ..Default::default()
+        };
+        let user = Preferences {
+            on_conflict: Some(ConflictPolicy::Skip),
+            output: Some(OutputFormat::Text),
+            editor: None,
+        };
+        let project_file = ValueSource::File(PathBuf::from("config.json"));
+        let user_file = ValueSource::File(PathBuf::from("/home/me/.config/creator/config.json"));
+
+        let resolved = resolve_preferences(&project, &user, &project_file, &user_file);
+        assert_eq!(
+            resolved.on_conflict,
+            (ConflictPolicy::Skip, user_file.clone())
+        );
+        assert_eq!(resolved.output, (OutputFormat::Json, project_file.clone()));
+        assert_eq!(
+            resolved.editor,
+            (Some("vim".to_string()), project_file.clone())
+        );
+
+        let resolved =
+            resolve_preferences(&project, &Preferences::default(), &project_file, &user_file);
+        assert_eq!(
+            resolved.on_conflict,
+            (ConflictPolicy::Overwrite, ValueSource::Default)
+        );
+        assert_eq!(resolved.on_conflict.1.to_string(), "default");
+        assert_eq!(project_file.to_string(), "file config.json");
     }
 
     #[test]
@@ -237,7 +866,7 @@ mod tests {
         fs::write(&config_path, config_content).unwrap();
 
         // Test CLI-first behavior: no commands = error, not interactive
-        let result = get_commands(None, &config_path);
+        let result = get_commands(None, &config_path, None);
         assert!(result.is_err());
 
         let error_msg = result.unwrap_err().to_string();
@@ -278,7 +907,7 @@ mod tests {
 
         // Test explicit interactive command
         let interactive_cmd = Some(Commands::Interactive);
-        let result = get_commands(interactive_cmd, &config_path);
+        let result = get_commands(interactive_cmd, &config_path, None);
         assert!(result.is_ok());
 
         if let Ok(Commands::Interactive) = result {
@@ -321,18 +950,31 @@ mod tests {
         // Test create command
         let create_cmd = Some(Commands::Create {
             path: "users/components/test".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
         });
-        let result = get_commands(create_cmd, &config_path);
+        let result = get_commands(create_cmd, &config_path, None);
         assert!(result.is_ok());
 
         // Test list command
         let list_cmd = Some(Commands::List { category: None });
-        let result = get_commands(list_cmd, &config_path);
+        let result = get_commands(list_cmd, &config_path, None);
         assert!(result.is_ok());
 
         // Test init command
-        let init_cmd = Some(Commands::Init { preset: None });
-        let result = get_commands(init_cmd, &config_path);
+        let init_cmd = Some(Commands::Init {
+            preset: None,
+            wizard: false,
+        });
+        let result = get_commands(init_cmd, &config_path, None);
         assert!(result.is_ok());
     }
 
@@ -367,7 +1009,7 @@ mod tests {
         fs::write(&config_path, config_content).unwrap();
 
         // Simulate CI/CD scenario - script calls creator without commands
-        let result = get_commands(None, &config_path);
+        let result = get_commands(None, &config_path, None);
 
         // Should fail fast with helpful error, not hang waiting for input
         assert!(result.is_err());
@@ -378,6 +1020,92 @@ mod tests {
         assert!(error_msg.contains("automation-friendly")); // Should explain why it's designed this way
     }
 
+    #[test]
+    fn test_workspace_project_selection() {
+        use crate::workspace::WORKSPACE_FILE;
+        use std::fs;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_path = temp_dir.path().join(WORKSPACE_FILE);
+        let workspace_content = r#"
+        {
+            "workspace": {
+                "name": "acme",
+                "version": "1.0",
+                "projects": [
+                    {
+                        "name": "web",
+                        "root": "apps/web",
+                        "source_dir": "app",
+                        "structure": {
+                            "modules": {
+                                "allow_dynamic_children": true,
+                                "default_structure": {
+                                    "components": {
+                                        "template": "templates/components.hbs",
+                                        "file_extension": "tsx"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                ]
+            }
+        }
+        "#;
+        fs::write(&workspace_path, workspace_content).unwrap();
+
+        let (project, source, source_dir) =
+            select_workspace_project(&workspace_path, Some("web".to_string())).unwrap();
+        assert_eq!(project, "web");
+        assert_eq!(source, ValueSource::Flag("--project"));
+        assert_eq!(source_dir, temp_dir.path().join("apps/web/app"));
+
+        // Commands validate the selected project's config
+        let list_cmd = Some(Commands::List { category: None });
+        assert!(get_commands(list_cmd, &workspace_path, Some("web")).is_ok());
+
+        let error_msg = select_workspace_project(&workspace_path, Some("api".to_string()))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Project 'api' not found"));
+    }
+
+    #[test]
+    fn test_command_flags_override_preferences() {
+        let mut project_config = ProjectConfig {
+            preferences: Some(Preferences {
+                on_conflict: Some(ConflictPolicy::Skip),
+                output: Some(OutputFormat::Json),
+                editor: Some("vim".to_string()),
+            }),
+            ..Default::default()
+        };
+        let create_cmd = Commands::Create {
+            path: "users/components/test".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: Some(ConflictPolicy::Error),
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+
+        apply_command_preferences(&mut project_config, &create_cmd);
+
+        let preferences = project_config.preferences();
+        assert_eq!(preferences.on_conflict, Some(ConflictPolicy::Error));
+        assert_eq!(preferences.output, Some(OutputFormat::Json));
+        assert_eq!(preferences.editor.as_deref(), Some("vim"));
+
+        assert_eq!(value_name(ConflictPolicy::Overwrite), "overwrite");
+    }
+
     #[test]
     fn test_config_validation_still_works() {
         use std::fs;
@@ -393,8 +1121,18 @@ mod tests {
         // All commands except Interactive should validate config early
         let create_cmd = Some(Commands::Create {
             path: "users/components/test".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
         });
-        let result = get_commands(create_cmd, &config_path);
+        let result = get_commands(create_cmd, &config_path, None);
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -403,7 +1141,7 @@ mod tests {
 
         // Interactive command should not validate config early (deferred validation)
         let interactive_cmd = Some(Commands::Interactive);
-        let result = get_commands(interactive_cmd, &config_path);
+        let result = get_commands(interactive_cmd, &config_path, None);
         assert!(result.is_ok()); // Should pass because validation is deferred
     }
 }
@@ -1,25 +1,258 @@
 use anyhow::{anyhow, Result};
-use inquire::{validator::Validation, Select, Text};
-use std::path::PathBuf;
-
-use crate::config::ProjectConfig;
-use crate::file_utils::{generate_template_name, is_valid_name, to_kebab_case};
+use inquire::{validator::Validation, Confirm, Select, Text};
+use serde_json::{Map, Value};
+use std::cell::OnceCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Category, ConflictPolicy, Item, OutputFormat, ProjectConfig};
+
+use crate::doctor::{check_project, Finding};
+use crate::file_utils::{
+    create_folder, list_subdirectories, resolve_file_name, resolve_template_name, to_kebab_case,
+};
 use crate::generator::Generator;
-use crate::opts::Commands;
+use crate::git;
+use crate::hooks::{fill_placeholders, hook_steps, run_hooks, HookContext, Stage};
+use crate::opts::{Commands, ConfigAction, ModuleAction};
+use crate::path_resolver::{resolve_tree_path, split_category_prefix, ResolvedItem};
+use crate::snapshots::{
+    check_snapshot, find_templates, load_fixtures, variables_as_assignments, Fixture, Outcome,
+};
+use crate::suggest::{closest, did_you_mean};
+use crate::validation::validate_name;
+use crate::variables::{
+    evaluate_condition, parse_assignments, prompt_variables, resolve_variables, TESTS_VARIABLE,
+};
+
+/// A file rendered by `creator render`, with the path `create` would write it to
+#[derive(Debug)]
+struct RenderedFile {
+    path: PathBuf,
+    template: PathBuf,
+    contents: String,
+}
+
+/// Help shown under interactive selects, which filter their options as you type
+const FUZZY_HELP: &str = "↑↓ to move, type to fuzzy search, enter to select";
+
+/// Where an interactively created item goes
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CreateTarget {
+    /// An existing module folder of a dynamic category
+    Module { category: String, module: String },
+    /// A module that doesn't exist yet
+    NewModule,
+    /// The static items of a category
+    Category { category: String },
+}
+
+impl CreateTarget {
+    /// Whether a create command targets this module or category. Modules are listed
+    /// first, so a path starting in a module matches the module before its category.
+    fn matches(&self, command: &Commands) -> bool {
+        let Commands::Create {
+            path,
+            category: Some(command_category),
+            ..
+        } = command
+        else {
+            return false;
+        };
+        let segments = path.split('/').count();
+
+        match self {
+            CreateTarget::Module { category, module } => {
+                segments >= 3
+                    && category == command_category
+                    && path
+                        .split('/')
+                        .next()
+                        .is_some_and(|first| to_kebab_case(first) == *module)
+            }
+            CreateTarget::NewModule => false,
+            CreateTarget::Category { category } => segments >= 2 && category == command_category,
+        }
+    }
+}
+
+impl std::fmt::Display for CreateTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CreateTarget::Module { category, module } => write!(f, "{} ({})", module, category),
+            CreateTarget::NewModule => write!(f, "➕ New module"),
+            CreateTarget::Category { category } => write!(f, "{} items (no module)", category),
+        }
+    }
+}
+
+/// A choice while walking down the category tree to the item type
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TreeChoice {
+    ItemType(String),
+    /// A nested category to go into
+    Category(String),
+    /// An existing module folder of a dynamic category
+    Module(String),
+    NewModule,
+}
+
+impl std::fmt::Display for TreeChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TreeChoice::ItemType(item_type) => write!(f, "{}", item_type),
+            TreeChoice::Category(category) => write!(f, "{}/", category),
+            TreeChoice::Module(module) => write!(f, "{}/ (module)", module),
+            TreeChoice::NewModule => write!(f, "➕ New module"),
+        }
+    }
+}
+
+/// Where the interactive walk is in the category tree
+struct TreeLevel<'a> {
+    category: &'a Category,
+    /// Folder of this level under the source directory
+    dir: PathBuf,
+    /// Path segments from the top-level category to this level
+    segments: Vec<String>,
+    /// Inside a module of a category without a module structure, whose item types
+    /// come from the default structure
+    in_module: bool,
+}
+
+impl<'a> TreeLevel<'a> {
+    fn new(category: &'a Category, dir: PathBuf) -> Self {
+        Self {
+            category,
+            dir,
+            segments: Vec::new(),
+            in_module: false,
+        }
+    }
+
+    /// What can be picked here: item types, nested categories and modules
+    fn choices(&self) -> Vec<TreeChoice> {
+        if self.in_module {
+            return self
+                .category
+                .get_default_structure()
+                .map(|items| items.keys().cloned().map(TreeChoice::ItemType).collect())
+                .unwrap_or_default();
+        }
+
+        let mut choices: Vec<TreeChoice> = self
+            .category
+            .get_item_names()
+            .into_iter()
+            .map(TreeChoice::ItemType)
+            .collect();
+        choices.extend(
+            self.category
+                .get_subcategory_names()
+                .into_iter()
+                .map(TreeChoice::Category),
+        );
+        if self.category.supports_dynamic_children() {
+            choices.extend(
+                list_subdirectories(&self.dir)
+                    .into_iter()
+                    .map(TreeChoice::Module),
+            );
+            choices.push(TreeChoice::NewModule);
+        }
+        choices
+    }
+
+    /// The item of an item type offered at this level
+    fn item(&self, item_type: &str) -> Option<&'a Item> {
+        if self.in_module {
+            self.category.get_default_structure()?.get(item_type)
+        } else {
+            self.category.get_item(item_type)
+        }
+    }
+
+    fn enter_category(&mut self, name: &str) {
+        if let Some(subcategory) = self.category.get_subcategory(name) {
+            self.category = subcategory;
+            self.dir.push(name);
+            self.segments.push(name.to_string());
+        }
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.dir.push(to_kebab_case(name));
+        self.segments.push(name.to_string());
+        match self.category.get_module_structure() {
+            Some(module_structure) => self.category = module_structure,
+            None => self.in_module = true,
+        }
+    }
+}
+
+/// Files written by a create, and those left alone because they already existed
+#[derive(Debug, Default)]
+struct GeneratedFiles {
+    created: Vec<PathBuf>,
+    skipped: Vec<PathBuf>,
+    /// Previous contents of the created files that overwrote existing ones
+    replaced: Vec<(PathBuf, Vec<u8>)>,
+    /// Outermost folder the create made, if the item's folder didn't exist
+    new_folder: Option<PathBuf>,
+}
+
+impl GeneratedFiles {
+    /// Undo the create: restore overwritten files, remove new ones and any folder it made
+    fn roll_back(&self) -> Result<()> {
+        for file in &self.created {
+            let result = match self.replaced.iter().find(|(path, _)| path == file) {
+                Some((_, contents)) => fs::write(file, contents),
+                None => fs::remove_file(file),
+            };
+            result.map_err(|e| anyhow!("Failed to roll back '{}': {}", file.display(), e))?;
+        }
+
+        if let Some(folder) = &self.new_folder {
+            fs::remove_dir_all(folder)
+                .map_err(|e| anyhow!("Failed to remove folder '{}': {}", folder.display(), e))?;
+        }
+        Ok(())
+    }
+}
 
 pub struct CliEngine {
     config: ProjectConfig,
     source_dir: PathBuf,
+    generator: OnceCell<Generator>,
 }
 
 impl CliEngine {
     /// Create new CLI engine with loaded config
     pub fn new(config: ProjectConfig, source_dir: PathBuf) -> Self {
-        Self { config, source_dir }
+        Self {
+            config,
+            source_dir,
+            generator: OnceCell::new(),
+        }
+    }
+
+    /// Get the template generator, loading shared partials and personal overrides of
+    /// them on first use
+    fn generator(&self) -> Result<&Generator> {
+        if let Some(generator) = self.generator.get() {
+            return Ok(generator);
+        }
+
+        let generator = Generator::new(self.config.templates_dir().as_deref())?;
+        if let Some(dir) = &self.config.template_overrides {
+            generator.register_overrides(dir)?;
+        }
+        Ok(self.generator.get_or_init(|| generator))
     }
 
-    /// Run interactive CLI to get user commands
-    pub fn run_interactive(&self) -> Result<Commands> {
+    /// Run interactive CLI to get user commands. Returns `None` when the user exits.
+    pub fn run_interactive(&self) -> Result<Option<Commands>> {
         println!("🚀 Creator v1.0 - Dynamic Config Loaded");
         println!("📋 Project: {}", self.config.project.name);
 
@@ -30,218 +263,577 @@ impl CliEngine {
         }
 
         // Main action selection
-        let actions = vec!["Create new item", "List structure", "Exit"];
+        let actions = vec![
+            "Create new item",
+            "Scaffold a module",
+            "Remove a module",
+            "List structure",
+            "Check the project (doctor)",
+            "Show resolved config",
+            "Migrate config to the current schema",
+            "Initialize a new config",
+            "Exit",
+        ];
         let selected_action = Select::new("What would you like to do?", actions)
+            .with_help_message(FUZZY_HELP)
             .prompt()
             .map_err(|_| anyhow!("Failed to select action"))?;
 
         match selected_action {
-            "Create new item" => self.interactive_create(),
-            "List structure" => Ok(Commands::List { category: None }),
-            "Exit" => std::process::exit(0),
+            "Create new item" => self.interactive_create(None).map(Some),
+            "Scaffold a module" => self.interactive_scaffold().map(Some),
+            "Remove a module" => self.interactive_remove().map(Some),
+            "List structure" => Ok(Some(Commands::List { category: None })),
+            "Check the project (doctor)" => Ok(Some(Commands::Doctor)),
+            "Show resolved config" => Ok(Some(Commands::Config {
+                action: ConfigAction::Show,
+            })),
+            "Migrate config to the current schema" => Ok(Some(Commands::Config {
+                action: ConfigAction::Migrate { dry_run: false },
+            })),
+            "Initialize a new config" => interactive_init().map(Some),
+            "Exit" => Ok(None),
             _ => Err(anyhow!("Invalid action selected")),
         }
     }
 
-    /// Interactive create flow - unified cohesive module API
-    fn interactive_create(&self) -> Result<Commands> {
+    /// Interactive create flow: pick where the item goes, its type and name.
+    /// `previous` is the last command of the session, whose module is preselected.
+    fn interactive_create(&self, previous: Option<&Commands>) -> Result<Commands> {
         println!("🏗️  Creating new item in cohesive module structure...");
-        println!("💡 Format: module/item_type/name");
         println!();
 
-        // Step 1: Get module name
-        let module_name = Text::new("Enter module name:")
-            .with_placeholder("e.g., cats, users, auth")
-            .with_validator(|input: &str| {
-                if input.trim().is_empty() {
-                    Ok(Validation::Invalid("Module name cannot be empty".into()))
-                } else if input.chars().any(|c| !c.is_alphanumeric() && c != '_' && c != '-') {
-                    Ok(Validation::Invalid("Module name can only contain alphanumeric characters, underscore, and dash".into()))
-                } else {
-                    Ok(Validation::Valid)
+        // Step 1: Pick an existing module, a new one or a category's static items
+        let targets = self.create_targets();
+        if targets.is_empty() {
+            return Err(anyhow!("No item types found in any module"));
+        }
+        let starting_cursor = previous
+            .and_then(|command| targets.iter().position(|target| target.matches(command)))
+            .unwrap_or(0);
+        let target = Select::new("Where should the item go?", targets)
+            .with_starting_cursor(starting_cursor)
+            .with_help_message(FUZZY_HELP)
+            .prompt()
+            .map_err(|_| anyhow!("Failed to select a module"))?;
+
+        let (category_name, module_name) = match target {
+            CreateTarget::Module { category, module } => (category, Some(module)),
+            CreateTarget::NewModule => {
+                let category = self.select_dynamic_category()?;
+                let module = self.prompt_module_name(&self.source_dir.join(&category))?;
+                (category, Some(module))
+            }
+            CreateTarget::Category { category } => (category, None),
+        };
+        let category = self
+            .config
+            .get_category(&category_name)
+            .ok_or_else(|| self.category_not_found(&category_name))?;
+
+        // Step 2: Walk down nested categories and modules to an item type
+        let mut level = TreeLevel::new(category, self.source_dir.join(&category_name));
+        if let Some(module_name) = &module_name {
+            level.enter_module(module_name);
+        }
+        let (item_type, item_config) = self.select_item_type(&category_name, &mut level)?;
+
+        // Step 3: Get item name
+        let item_rules: Vec<_> = self
+            .config
+            .item_validation(item_config)
+            .into_iter()
+            .cloned()
+            .collect();
+        let existing_modules = self.existing_modules();
+        let item_name = Text::new(&format!("Enter name for {}:", item_type))
+            .with_placeholder("e.g., cat-list, user-profile")
+            .with_validator(move |input: &str| {
+                let rules: Vec<_> = item_rules.iter().collect();
+                match validate_name("item", input, &rules, &existing_modules) {
+                    Ok(()) => Ok(Validation::Valid),
+                    Err(message) => Ok(Validation::Invalid(message.into())),
                 }
             })
             .prompt()
-            .map_err(|_| anyhow!("Failed to get module name"))?;
+            .map_err(|_| anyhow!("Failed to get item name"))?;
 
-        // Step 2: Collect all available item types from all categories
-        let mut all_item_types = Vec::new();
+        // Step 4: Prompt for item variables
+        let set = prompt_variables(item_config.variables.as_ref())?;
 
-        for category_name in self.config.get_categories() {
-            if let Some(category) = self.config.get_category(&category_name) {
-                // Add static items
-                let static_items = category.get_item_names();
-                for item in static_items {
-                    all_item_types.push(format!("{} ({})", item, category_name));
-                }
+        // Build the path relative to the category: [modules and categories/]item_type/name
+        let path = level
+            .segments
+            .iter()
+            .chain([&item_type, &item_name])
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("/");
 
-                // Add dynamic items
-                if category.supports_dynamic_children() {
-                    if let Some(default_structure) = category.get_default_structure() {
-                        for item in default_structure.keys() {
-                            all_item_types.push(format!("{} ({})", item, category_name));
-                        }
-                    }
+        println!();
+        println!("📁 Will create: {}:{}", category_name, path);
+
+        Ok(Commands::Create {
+            path,
+            set,
+            no_tests: false,
+            category: Some(category_name),
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        })
+    }
+
+    /// Ask for the category and name of a module to scaffold
+    fn interactive_scaffold(&self) -> Result<Commands> {
+        let category = self.select_dynamic_category()?;
+        let name = self.prompt_new_module_name()?;
+        Ok(Commands::Module {
+            action: ModuleAction::Scaffold {
+                name,
+                category: Some(category),
+            },
+        })
+    }
+
+    /// Pick an existing module to remove. The removal itself asks for confirmation.
+    fn interactive_remove(&self) -> Result<Commands> {
+        let modules: Vec<CreateTarget> = self
+            .create_targets()
+            .into_iter()
+            .filter(|target| matches!(target, CreateTarget::Module { .. }))
+            .collect();
+        if modules.is_empty() {
+            return Err(anyhow!("No module folders found to remove"));
+        }
+
+        let target = Select::new("Which module should be removed?", modules)
+            .with_help_message(FUZZY_HELP)
+            .prompt()
+            .map_err(|_| anyhow!("Failed to select a module"))?;
+        let CreateTarget::Module { category, module } = target else {
+            return Err(anyhow!("Invalid module selected"));
+        };
+        Ok(Commands::Module {
+            action: ModuleAction::Remove {
+                name: module,
+                category: Some(category),
+                yes: false,
+            },
+        })
+    }
+
+    /// Prompt level by level until an item type is picked, going into nested
+    /// categories and modules along the way
+    fn select_item_type<'a>(
+        &self,
+        category_name: &str,
+        level: &mut TreeLevel<'a>,
+    ) -> Result<(String, &'a Item)> {
+        loop {
+            let choices = level.choices();
+            if choices.is_empty() {
+                let location = std::iter::once(category_name)
+                    .chain(level.segments.iter().map(String::as_str))
+                    .collect::<Vec<_>>()
+                    .join("/");
+                return Err(anyhow!("No item types found in '{}'", location));
+            }
+
+            let choice = Select::new("Select item type:", choices)
+                .with_help_message(FUZZY_HELP)
+                .prompt()
+                .map_err(|_| anyhow!("Failed to select item type"))?;
+            match choice {
+                TreeChoice::ItemType(item_type) => {
+                    let item = level
+                        .item(&item_type)
+                        .ok_or_else(|| anyhow!("Unknown item type '{}'", item_type))?;
+                    return Ok((item_type, item));
+                }
+                TreeChoice::Category(name) => level.enter_category(&name),
+                TreeChoice::Module(name) => level.enter_module(&name),
+                TreeChoice::NewModule => {
+                    let name = self.prompt_module_name(&level.dir)?;
+                    level.enter_module(&name);
                 }
             }
         }
+    }
 
-        if all_item_types.is_empty() {
-            return Err(anyhow!("No item types found in any module"));
+    /// Everywhere an item can be created interactively: existing module folders,
+    /// a new module when a category allows them, and categories with static items
+    fn create_targets(&self) -> Vec<CreateTarget> {
+        let mut modules = Vec::new();
+        let mut categories = Vec::new();
+        let mut allows_modules = false;
+
+        for category_name in self.config.get_categories() {
+            let Some(category) = self.config.get_category(&category_name) else {
+                continue;
+            };
+
+            if category.supports_dynamic_children() {
+                allows_modules = true;
+                modules.extend(
+                    list_subdirectories(&self.source_dir.join(&category_name))
+                        .into_iter()
+                        .map(|module| CreateTarget::Module {
+                            category: category_name.clone(),
+                            module,
+                        }),
+                );
+            }
+            if !category.get_item_names().is_empty() || !category.get_subcategory_names().is_empty()
+            {
+                categories.push(CreateTarget::Category {
+                    category: category_name,
+                });
+            }
         }
 
-        all_item_types.sort();
+        if allows_modules {
+            modules.push(CreateTarget::NewModule);
+        }
+        modules.extend(categories);
+        modules
+    }
 
-        // Step 3: Select item type
-        let selected_item_display = Select::new("Select item type:", all_item_types)
-            .prompt()
-            .map_err(|_| anyhow!("Failed to select item type"))?;
+    /// Pick the category a new module goes in, skipping the prompt when there's only one
+    fn select_dynamic_category(&self) -> Result<String> {
+        let mut categories: Vec<String> = self
+            .config
+            .get_categories()
+            .into_iter()
+            .filter(|name| {
+                self.config
+                    .get_category(name)
+                    .is_some_and(|category| category.supports_dynamic_children())
+            })
+            .collect();
+
+        match categories.len() {
+            0 => Err(anyhow!("No category allows creating modules")),
+            1 => Ok(categories.remove(0)),
+            _ => Select::new("Which category should the module go in?", categories)
+                .with_help_message(FUZZY_HELP)
+                .prompt()
+                .map_err(|_| anyhow!("Failed to select a category")),
+        }
+    }
 
-        // Extract item type from "item_type (module)" format
-        let item_type = selected_item_display
-            .split(" (")
-            .next()
-            .ok_or_else(|| anyhow!("Invalid item type format"))?;
+    /// Ask for a new module name in the `dir` of its category, validated against the
+    /// configured rules
+    fn prompt_module_name(&self, dir: &Path) -> Result<String> {
+        let module_name = self.prompt_new_module_name()?;
+        self.confirm_module_name(module_name, dir)
+    }
 
-        // Step 4: Get item name
-        let item_name = Text::new(&format!("Enter name for {}:", item_type))
-            .with_placeholder("e.g., cat-list, user-profile")
-            .with_validator(|input: &str| {
-                if input.trim().is_empty() {
-                    Ok(Validation::Invalid("Item name cannot be empty".into()))
-                } else if input
-                    .chars()
-                    .any(|c| !c.is_alphanumeric() && c != '_' && c != '-')
-                {
-                    Ok(Validation::Invalid(
-                        "Item name can only contain alphanumeric characters, underscore, and dash"
-                            .into(),
-                    ))
-                } else {
-                    Ok(Validation::Valid)
+    /// Ask for a module name, validated against the configured rules
+    fn prompt_new_module_name(&self) -> Result<String> {
+        let module_rules: Vec<_> = self
+            .config
+            .module_validation()
+            .into_iter()
+            .cloned()
+            .collect();
+        Text::new("Enter module name:")
+            .with_placeholder("e.g., cats, users, auth")
+            .with_validator(move |input: &str| {
+                let rules: Vec<_> = module_rules.iter().collect();
+                match validate_name("module", input, &rules, &[]) {
+                    Ok(()) => Ok(Validation::Valid),
+                    Err(message) => Ok(Validation::Invalid(message.into())),
                 }
             })
             .prompt()
-            .map_err(|_| anyhow!("Failed to get item name"))?;
+            .map_err(|_| anyhow!("Failed to get module name"))
+    }
+
+    /// Offer the closest module of the same category folder when the entered one
+    /// doesn't exist
+    fn confirm_module_name(&self, module_name: String, dir: &Path) -> Result<String> {
+        let existing_modules = list_subdirectories(dir);
+        if existing_modules.contains(&to_kebab_case(&module_name)) {
+            return Ok(module_name);
+        }
 
-        // Build path in module/item_type/name format
-        let path = format!("{}/{}/{}", module_name, item_type, item_name);
+        let Some(similar) = closest(&module_name, existing_modules.iter().map(String::as_str))
+        else {
+            return Ok(module_name);
+        };
 
-        println!();
-        println!("📁 Will create: {}", path);
+        let use_existing = format!("Use existing module '{}'", similar);
+        let create_new = format!("Create new module '{}'", module_name);
+        let choice = Select::new(
+            &format!(
+                "Module '{}' doesn't exist. Did you mean '{}'?",
+                module_name, similar
+            ),
+            vec![use_existing.clone(), create_new],
+        )
+        .prompt()
+        .map_err(|_| anyhow!("Failed to confirm module name"))?;
 
-        Ok(Commands::Create { path })
+        Ok(if choice == use_existing {
+            similar.to_string()
+        } else {
+            module_name
+        })
     }
 
-    /// Handle create command execution - unified API for cohesive modules
+    /// Handle create command execution - unified API for cohesive modules.
+    /// `--on-conflict` and `--output` are merged into the config preferences by the caller.
     pub fn handle_create(&self, cmd: Commands) -> Result<()> {
-        if let Commands::Create { path } = cmd {
-            println!("🏗️  Creating item from path: {}", path);
+        self.create_item(cmd).map(|_| ())
+    }
 
-            // Parse path: module/item_type/name
-            let parts: Vec<&str> = path.split('/').collect();
-            if parts.len() != 3 {
-                return Err(anyhow!(
-                    "Invalid path format. Expected: module/item_type/name, got: {}\n💡 Example: cats/components/cat-list",
-                    path
-                ));
+    /// Create the item a create command describes and report the files written
+    fn create_item(&self, cmd: Commands) -> Result<GeneratedFiles> {
+        if let Commands::Create {
+            path,
+            set,
+            no_tests,
+            category,
+            open,
+            no_hooks,
+            stage,
+            commit,
+            allow_dirty,
+            ..
+        } = cmd
+        {
+            let output = self.config.preferences().output.unwrap_or_default();
+            if output == OutputFormat::Text {
+                println!("🏗️  Creating item from path: {}", path);
             }
 
-            let first_part = parts[0];
-            let second_part = parts[1];
-            let third_part = parts[2];
-
-            // Check if first part is a static category
-            let (category_name, category, module_name, item_type, item_name) =
-                if let Some(category) = self.config.get_category(first_part) {
-                    if !category.supports_dynamic_children() {
-                        // Static category: category/item_type/item_name
-                        (
-                            first_part.to_string(),
-                            category,
-                            first_part,
-                            second_part,
-                            third_part,
-                        )
-                    } else {
-                        // Dynamic category specified: treat as module_name/item_type/item_name
-                        let item_type = second_part;
-                        let (cat_name, cat) = self.find_category_for_item_type(item_type)?;
-                        (cat_name, cat, first_part, second_part, third_part)
-                    }
-                } else {
-                    // Normal case: module_name/item_type/item_name
-                    let item_type = second_part;
-                    let (cat_name, cat) = self.find_category_for_item_type(item_type)?;
-                    (cat_name, cat, first_part, second_part, third_part)
-                };
+            let provided_variables = parse_assignments(&set)?;
 
-            // Validate names contain only valid characters
-            if !is_valid_name(module_name) {
-                return Err(anyhow!(
-                    "Invalid module name '{}'. Use only letters, numbers, hyphens, and underscores.",
-                    module_name
-                ));
+            let resolved = self.resolve_create_path(&path, category.as_deref())?;
+
+            // Validate module names against built-in and configured rules
+            for module_name in &resolved.modules {
+                validate_name("module", module_name, &self.config.module_validation(), &[])
+                    .map_err(|message| anyhow!(message))?;
             }
 
-            if !is_valid_name(item_name) {
-                return Err(anyhow!(
-                    "Invalid item name '{}'. Use only letters, numbers, hyphens, and underscores.",
-                    item_name
-                ));
+            if let ([module_name], OutputFormat::Text) = (resolved.modules.as_slice(), output) {
+                self.warn_about_similar_module(&resolved.category_name, module_name);
+            }
+
+            let item_type = resolved.item_type.as_str();
+            let item_name = resolved.item_name.as_str();
+            let item_config = resolved.item;
+
+            // Validate item name against project-wide and item-specific rules
+            validate_name(
+                "item",
+                item_name,
+                &self.config.item_validation(item_config),
+                &self.existing_modules(),
+            )
+            .map_err(|message| anyhow!(message))?;
+
+            let mut variables =
+                resolve_variables(item_config.variables.as_ref(), &provided_variables)?;
+            variables.insert(TESTS_VARIABLE.to_string(), Value::Bool(!no_tests));
+
+            let project_dir = self.project_dir();
+            let (project_hooks, item_hooks) = if no_hooks {
+                (None, None)
+            } else {
+                (
+                    self.config.project.hooks.as_ref(),
+                    item_config.hooks.as_ref(),
+                )
+            };
+
+            // Command flags add to the configured git options; committing implies staging
+            let git_options = self.config.project.git.clone().unwrap_or_default();
+            let require_clean = git_options.require_clean.unwrap_or(false) && !allow_dirty;
+            let commit = commit || git_options.commit.unwrap_or(false);
+            let stage = stage || commit || git_options.stage.unwrap_or(false);
+            if require_clean || stage {
+                git::ensure_repository(&project_dir)?;
+            }
+
+            let (_, outputs) = self.item_outputs(
+                &resolved.item_dir(&self.source_dir),
+                resolved.file_name_case_category(),
+                item_type,
+                item_name,
+                item_config,
+                &variables,
+            )?;
+            let planned: Vec<PathBuf> = outputs
+                .into_iter()
+                .map(|(_, path)| self.project_path(&path))
+                .collect();
+
+            if require_clean {
+                let dirty = git::dirty_files(&project_dir, &planned)?;
+                if !dirty.is_empty() {
+                    return Err(anyhow!(
+                        "Refusing to create: files about to be written have uncommitted changes:\n{}\n💡 Commit or stash them first, or pass --allow-dirty",
+                        dirty
+                            .iter()
+                            .map(|file| format!("   • {}", file.display()))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    ));
+                }
             }
 
-            // Get item configuration
-            let item_config = if let Some(static_item) = category.get_item(item_type) {
-                static_item
-            } else if category.supports_dynamic_children() {
-                let default_structure = category.get_default_structure().ok_or_else(|| {
+            // Pre-generate hooks see the files about to be written and can cancel the create
+            let pre_steps = hook_steps(project_hooks, item_hooks, Stage::PreGenerate);
+            if !pre_steps.is_empty() {
+                let context = HookContext {
+                    files: &planned,
+                    name: item_name,
+                    item_type,
+                    module: resolved.module_name(),
+                };
+                run_hooks(
+                    &pre_steps,
+                    Stage::PreGenerate,
+                    &context,
+                    &project_dir,
+                    output == OutputFormat::Text,
+                )
+                .map_err(|e| {
                     anyhow!(
-                        "Category '{}' supports dynamic children but has no default structure",
-                        category_name
+                        "Create cancelled: {}\n💡 Nothing was written. Pass --no-hooks to skip hooks",
+                        e
                     )
                 })?;
-
-                default_structure.get(item_type).ok_or_else(|| {
-                    anyhow!("Item type '{}' not found in default structure", item_type)
-                })?
-            } else {
-                return Err(anyhow!(
-                    "Item type '{}' not found in category '{}'",
-                    item_type,
-                    category_name
-                ));
-            };
+            }
 
             // Create the item using the appropriate structure
-            if category.supports_dynamic_children() {
+            let generated = match (resolved.categories.len(), resolved.modules.as_slice()) {
                 // Dynamic category: category/module_name/item_type/item_name.ext
-                self.create_cohesive_module_item(
-                    &category_name,
+                (1, [module_name]) => self.create_cohesive_module_item(
+                    &resolved.category_name,
                     module_name,
                     item_type,
                     item_name,
                     item_config,
-                )?;
-            } else {
+                    &variables,
+                )?,
                 // Static category: category/item_type/item_name.ext
-                self.create_static_category_item(
-                    &category_name,
+                (1, []) => self.create_static_category_item(
+                    &resolved.category_name,
                     item_type,
                     item_name,
                     item_config,
-                )?;
+                    &variables,
+                )?,
+                // Nested categories: category/(subcategory|module)/.../item_type/item_name.ext
+                _ => self.write_item_files(
+                    &resolved.item_dir(&self.source_dir),
+                    resolved.file_name_case_category(),
+                    item_type,
+                    item_name,
+                    item_config,
+                    &variables,
+                )?,
+            };
+
+            // Post-generate hooks see the files created; skipped files were left as they were
+            let created: Vec<PathBuf> = generated
+                .created
+                .iter()
+                .map(|path| self.project_path(path))
+                .collect();
+            let context = HookContext {
+                files: &created,
+                name: item_name,
+                item_type,
+                module: resolved.module_name(),
+            };
+            let post_steps = hook_steps(project_hooks, item_hooks, Stage::PostGenerate);
+            if !post_steps.is_empty() && !created.is_empty() {
+                if let Err(e) = run_hooks(
+                    &post_steps,
+                    Stage::PostGenerate,
+                    &context,
+                    &project_dir,
+                    output == OutputFormat::Text,
+                ) {
+                    generated.roll_back()?;
+                    return Err(anyhow!(
+                        "{}\n💡 Rolled back {} file(s). Fix the hook, set its on_failure to \"warn\", or pass --no-hooks",
+                        e,
+                        generated.created.len()
+                    ));
+                }
             }
 
-            println!(
-                "✅ Successfully created {} '{}' in module '{}'",
-                item_type, item_name, module_name
-            );
+            // Stage after the hooks so formatted files are what gets committed
+            let staged = stage && !created.is_empty();
+            let mut commit_message = None;
+            if staged {
+                git::stage(&project_dir, &created)
+                    .map_err(|e| anyhow!("Created the files, but staging them failed: {}", e))?;
+                if commit {
+                    let template = git_options
+                        .commit_message
+                        .as_deref()
+                        .unwrap_or(git::DEFAULT_COMMIT_MESSAGE);
+                    let message = fill_placeholders(template, &context, str::to_string);
+                    git::commit(&project_dir, &created, &message).map_err(|e| {
+                        anyhow!("Created and staged the files, but the commit failed: {}", e)
+                    })?;
+                    commit_message = Some(message);
+                }
+            }
+
+            match output {
+                OutputFormat::Text => {
+                    println!(
+                        "✅ Successfully created {} '{}' in module '{}'",
+                        item_type,
+                        item_name,
+                        resolved.module_name()
+                    );
+                    for file in &generated.created {
+                        println!("   📄 {}", file.display());
+                    }
+                    for file in &generated.skipped {
+                        println!("   ⏭️  {} (already exists, skipped)", file.display());
+                    }
+                    match &commit_message {
+                        Some(message) => println!("📝 Committed: {}", message),
+                        None if staged => println!("📌 Staged {} file(s)", created.len()),
+                        None => {}
+                    }
+                }
+                OutputFormat::Json => {
+                    let summary = serde_json::json!({
+                        "item_type": item_type,
+                        "name": item_name,
+                        "module": resolved.module_name(),
+                        "created": generated.created,
+                        "skipped": generated.skipped,
+                        "staged": staged,
+                        "commit": commit_message,
+                    });
+                    println!("{}", summary);
+                }
+            }
+
+            if open {
+                self.open_in_editor(&generated.created)?;
+            }
+
+            Ok(generated)
         } else {
-            return Err(anyhow!("Invalid command for create handler"));
+            Err(anyhow!("Invalid command for create handler"))
         }
-
-        Ok(())
     }
 
     /// Handle list command execution
@@ -261,22 +853,246 @@ impl CliEngine {
         Ok(())
     }
 
-    /// Handle interactive command execution
-    pub fn handle_interactive(&self) -> Result<()> {
+    /// Handle interactive command execution. Creates, module changes and lists are run
+    /// here; commands that work on the config file itself (init, config, doctor) are
+    /// returned for the caller.
+    pub fn handle_interactive(&self) -> Result<Option<Commands>> {
         // Run the interactive flow and execute the returned command
-        let interactive_command = self.run_interactive()?;
+        let Some(interactive_command) = self.run_interactive()? else {
+            return Ok(None);
+        };
 
         // Execute the command chosen interactively
         match interactive_command {
-            Commands::Create { .. } => self.handle_create(interactive_command)?,
+            Commands::Create { .. } => self.create_until_done(interactive_command)?,
+            Commands::Module { .. } => self.handle_module(interactive_command)?,
             Commands::List { .. } => self.handle_list(interactive_command)?,
+            Commands::Render { .. } => self.handle_render(interactive_command)?,
+            Commands::TestTemplates { update } => self.handle_test_templates(update)?,
+            Commands::Init { .. } | Commands::Config { .. } | Commands::Doctor => {
+                return Ok(Some(interactive_command));
+            }
             Commands::Interactive => {
                 // Prevent infinite recursion - should not happen
                 return Err(anyhow!("Interactive mode cannot call itself"));
             }
-            Commands::Init { .. } => {
-                // Init command not supported in interactive mode for now
-                return Err(anyhow!("Init command not available in interactive mode"));
+            Commands::Completions { .. } | Commands::Complete { .. } => {
+                return Err(anyhow!(
+                    "Completion commands not available in interactive mode"
+                ));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Handle module command execution: scaffold or remove a module folder
+    pub fn handle_module(&self, cmd: Commands) -> Result<()> {
+        let Commands::Module { action } = cmd else {
+            return Err(anyhow!("Invalid command for module handler"));
+        };
+
+        match action {
+            ModuleAction::Scaffold { name, category } => {
+                self.scaffold_module(&name, category.as_deref()).map(|_| ())
+            }
+            ModuleAction::Remove {
+                name,
+                category,
+                yes,
+            } => self.remove_module(&name, category.as_deref(), yes),
+        }
+    }
+
+    /// Check the project for `creator doctor`
+    pub fn doctor(&self) -> Vec<Finding> {
+        check_project(&self.config, &self.source_dir, &self.project_dir())
+    }
+
+    /// Create a module folder with a folder for each of its item types and nested
+    /// categories, and return the folders created
+    fn scaffold_module(&self, name: &str, category: Option<&str>) -> Result<Vec<PathBuf>> {
+        let (prefix, name) = split_category_prefix(name);
+        let category_name = match explicit_category(prefix, category)? {
+            Some(category_name) => {
+                self.dynamic_category(category_name)?;
+                category_name.to_string()
+            }
+            None => {
+                let mut categories = self.dynamic_categories();
+                match categories.len() {
+                    0 => return Err(anyhow!("No category allows creating modules")),
+                    1 => categories.remove(0),
+                    _ => {
+                        return Err(anyhow!(
+                            "Several categories allow modules: {}.\n💡 Choose one with a category prefix or --category, e.g.:\n  creator module scaffold {}:{}",
+                            categories.join(", "),
+                            categories[0],
+                            name
+                        ))
+                    }
+                }
+            }
+        };
+        let category = self.dynamic_category(&category_name)?;
+
+        validate_name("module", name, &self.config.module_validation(), &[])
+            .map_err(|message| anyhow!(message))?;
+
+        let module_dir = self
+            .source_dir
+            .join(&category_name)
+            .join(to_kebab_case(name));
+        if module_dir.exists() {
+            return Err(anyhow!(
+                "Module '{}' already exists at {}",
+                name,
+                module_dir.display()
+            ));
+        }
+        self.warn_about_similar_module(&category_name, name);
+
+        let folders: Vec<PathBuf> = std::iter::once(module_dir.clone())
+            .chain(
+                module_folders(category)
+                    .into_iter()
+                    .map(|folder| module_dir.join(folder)),
+            )
+            .collect();
+        for folder in &folders {
+            create_folder(folder)?;
+        }
+
+        println!(
+            "✅ Scaffolded module '{}' in '{}': {}",
+            name,
+            category_name,
+            module_dir.display()
+        );
+        for folder in &folders[1..] {
+            println!("   📁 {}", folder.display());
+        }
+
+        Ok(folders)
+    }
+
+    /// Delete a module folder and everything in it, after confirming unless `yes`
+    fn remove_module(&self, name: &str, category: Option<&str>, yes: bool) -> Result<()> {
+        let (prefix, name) = split_category_prefix(name);
+        validate_name("module", name, &self.config.module_validation(), &[])
+            .map_err(|message| anyhow!(message))?;
+        let folder = to_kebab_case(name);
+        let categories = match explicit_category(prefix, category)? {
+            Some(category_name) => {
+                self.dynamic_category(category_name)?;
+                vec![category_name.to_string()]
+            }
+            None => self.dynamic_categories(),
+        };
+
+        let mut owning: Vec<String> = categories
+            .iter()
+            .filter(|category_name| self.source_dir.join(category_name).join(&folder).is_dir())
+            .cloned()
+            .collect();
+        let category_name = match owning.len() {
+            0 => {
+                let modules: Vec<String> = categories
+                    .iter()
+                    .flat_map(|category_name| list_subdirectories(&self.source_dir.join(category_name)))
+                    .collect();
+                return Err(anyhow!(
+                    "Module '{}' not found in {}.\n{}💡 Existing modules: {}",
+                    name,
+                    categories.join(", "),
+                    did_you_mean(name, modules.iter().map(String::as_str)),
+                    if modules.is_empty() {
+                        "none".to_string()
+                    } else {
+                        modules.join(", ")
+                    }
+                ));
+            }
+            1 => owning.remove(0),
+            _ => {
+                return Err(anyhow!(
+                    "Module '{}' exists in several categories: {}.\n💡 Choose one with a category prefix or --category, e.g.:\n  creator module remove {}:{}",
+                    name,
+                    owning.join(", "),
+                    owning[0],
+                    name
+                ))
+            }
+        };
+
+        let category_dir = self.source_dir.join(&category_name);
+        let module_dir = category_dir.join(&folder);
+        if !is_child_dir(&category_dir, &module_dir) {
+            return Err(anyhow!(
+                "'{}' is not a module folder of '{}'",
+                module_dir.display(),
+                category_name
+            ));
+        }
+        let files = count_files(&module_dir);
+        if !yes {
+            let confirmed = Confirm::new(&format!(
+                "Remove {} and the {} file(s) in it?",
+                module_dir.display(),
+                files
+            ))
+            .with_default(false)
+            .prompt()
+            .map_err(|_| {
+                anyhow!("Failed to confirm removal\n💡 Pass --yes to remove without asking")
+            })?;
+            if !confirmed {
+                println!("Kept {}", module_dir.display());
+                return Ok(());
+            }
+        }
+
+        fs::remove_dir_all(&module_dir)
+            .map_err(|e| anyhow!("Failed to remove '{}': {}", module_dir.display(), e))?;
+        println!(
+            "🗑️  Removed module '{}' from '{}' ({} file(s))",
+            name, category_name, files
+        );
+
+        Ok(())
+    }
+
+    /// Create items one after another until the user is done, then summarize them.
+    /// A failed create is reported and doesn't end the session.
+    fn create_until_done(&self, first: Commands) -> Result<()> {
+        let mut created = Vec::new();
+        let mut command = first;
+
+        loop {
+            let label = create_label(&command);
+            match self.create_item(command.clone()) {
+                Ok(generated) => created.push((label, generated.created.len())),
+                Err(e) => eprintln!("❌ {}", e),
+            }
+
+            println!();
+            let another = Confirm::new("Create another item?")
+                .with_default(true)
+                .prompt()
+                .unwrap_or(false);
+            if !another {
+                break;
+            }
+            command = self.interactive_create(Some(&command))?;
+        }
+
+        println!();
+        if created.is_empty() {
+            println!("No items created.");
+        } else {
+            println!("🎉 Created {} item(s):", created.len());
+            for (label, files) in &created {
+                println!("   • {} ({} file(s))", label, files);
             }
         }
 
@@ -309,6 +1125,13 @@ impl CliEngine {
                     }
                 }
 
+                if has_nested_structure(category) {
+                    println!("   Nested paths:");
+                    for line in describe_layout(category, &category_name) {
+                        println!("     {}", line);
+                    }
+                }
+
                 println!();
             }
         }
@@ -321,7 +1144,7 @@ impl CliEngine {
         let category = self
             .config
             .get_category(category_name)
-            .ok_or_else(|| anyhow!("Category '{}' not found", category_name))?;
+            .ok_or_else(|| self.category_not_found(category_name))?;
 
         println!("📁 Module: {}", category_name);
 
@@ -360,6 +1183,14 @@ impl CliEngine {
             }
         }
 
+        // List nested categories and module structures
+        if has_nested_structure(category) {
+            println!("🌳 Nested paths:");
+            for line in describe_layout(category, category_name) {
+                println!("   • {}", line);
+            }
+        }
+
         Ok(())
     }
 
@@ -371,9 +1202,8 @@ impl CliEngine {
         item_type: &str,
         item_name: &str,
         item_config: &crate::config::Item,
-    ) -> Result<()> {
-        use crate::file_utils::{create_file, create_folder};
-
+        variables: &Map<String, Value>,
+    ) -> Result<GeneratedFiles> {
         // Build path: source_dir/category/module_name/item_type/
         let item_path = self
             .source_dir
@@ -381,20 +1211,14 @@ impl CliEngine {
             .join(to_kebab_case(module_name))
             .join(item_type);
 
-        // Create folder structure
-        create_folder(&item_path)?;
-
-        // Generate file from template
-        let template_path = PathBuf::from(&item_config.template);
-        let file_path = item_path
-            .join(to_kebab_case(item_name))
-            .with_extension(&item_config.file_extension);
-
-        let template_name = generate_template_name(item_type, item_name);
-        let template_content = Generator::generate(&template_path, template_name)?;
-        create_file(&file_path, template_content)?;
-
-        Ok(())
+        self.write_item_files(
+            &item_path,
+            self.config.get_category(category),
+            item_type,
+            item_name,
+            item_config,
+            variables,
+        )
     }
 
     /// Create item in static category structure: category/item_type/item_name.ext
@@ -404,80 +1228,764 @@ impl CliEngine {
         item_type: &str,
         item_name: &str,
         item_config: &crate::config::Item,
-    ) -> Result<()> {
-        use crate::file_utils::{create_file, create_folder};
-
+        variables: &Map<String, Value>,
+    ) -> Result<GeneratedFiles> {
         // Build path: source_dir/category/item_type/
         let item_path = self.source_dir.join(category).join(item_type);
 
-        // Create folder structure
-        create_folder(&item_path)?;
+        self.write_item_files(
+            &item_path,
+            self.config.get_category(category),
+            item_type,
+            item_name,
+            item_config,
+            variables,
+        )
+    }
 
-        // Generate file from template
-        let template_path = PathBuf::from(&item_config.template);
-        let file_path = item_path
-            .join(to_kebab_case(item_name))
-            .with_extension(&item_config.file_extension);
+    /// Render the item's main file plus every extra file whose condition holds,
+    /// applying the conflict policy to files that already exist.
+    fn write_item_files(
+        &self,
+        item_path: &Path,
+        category: Option<&Category>,
+        item_type: &str,
+        item_name: &str,
+        item_config: &crate::config::Item,
+        variables: &Map<String, Value>,
+    ) -> Result<GeneratedFiles> {
+        use crate::file_utils::{create_file, create_folder};
 
-        let template_name = generate_template_name(item_type, item_name);
-        let template_content = Generator::generate(&template_path, template_name)?;
-        create_file(&file_path, template_content)?;
+        let (template_name, outputs) = self.item_outputs(
+            item_path,
+            category,
+            item_type,
+            item_name,
+            item_config,
+            variables,
+        )?;
+
+        // Refuse before writing anything so a conflict never leaves a partial item
+        let on_conflict = self.config.preferences().on_conflict.unwrap_or_default();
+        if on_conflict == ConflictPolicy::Error {
+            if let Some((_, existing)) = outputs.iter().find(|(_, path)| path.exists()) {
+                return Err(anyhow!(
+                    "File '{}' already exists.\n💡 Use --on-conflict overwrite or --on-conflict skip",
+                    existing.display()
+                ));
+            }
+        }
 
-        Ok(())
+        // Render every file before writing any, so a broken template leaves nothing behind
+        let mut rendered = Vec::new();
+        let mut skipped = Vec::new();
+        for (template, file_path) in outputs {
+            if on_conflict == ConflictPolicy::Skip && file_path.exists() {
+                skipped.push(file_path);
+                continue;
+            }
+            let template_path = self.config.resolve_template_path(template);
+            let contents =
+                self.generator()?
+                    .render(&template_path, template_name.clone(), variables)?;
+            rendered.push((file_path, contents));
+        }
+
+        // Create folder structure, remembering what didn't exist for a rollback
+        let new_folder = item_path
+            .ancestors()
+            .take_while(|folder| !folder.exists())
+            .last()
+            .map(Path::to_path_buf);
+        create_folder(item_path)?;
+
+        let mut generated = GeneratedFiles {
+            skipped,
+            new_folder,
+            ..Default::default()
+        };
+        for (file_path, contents) in rendered {
+            if let Ok(previous) = fs::read(&file_path) {
+                generated.replaced.push((file_path.clone(), previous));
+            }
+            if let Err(e) = create_file(&file_path, contents) {
+                // Count the failed file as created so its partial contents are undone too
+                generated.created.push(file_path);
+                generated.roll_back()?;
+                return Err(e);
+            }
+            generated.created.push(file_path);
+        }
+
+        Ok(generated)
     }
 
-    /// Find category that contains the specified item type
-    fn find_category_for_item_type(
+    /// Template name of an item and the files it produces in `item_path`, each with
+    /// its template: the main output first, then extra files whose condition holds
+    fn item_outputs<'i>(
         &self,
+        item_path: &Path,
+        category: Option<&Category>,
         item_type: &str,
-    ) -> Result<(String, &crate::config::Category)> {
-        // First, check dynamic categories (they have priority for cohesive modules)
-        for category_name in self.config.get_categories() {
-            if let Some(category) = self.config.get_category(&category_name) {
-                if category.supports_dynamic_children() {
-                    if let Some(default_structure) = category.get_default_structure() {
-                        if default_structure.contains_key(item_type) {
-                            return Ok((category_name, category));
-                        }
-                    }
-                }
+        item_name: &str,
+        item_config: &'i crate::config::Item,
+        variables: &Map<String, Value>,
+    ) -> Result<(String, Vec<(&'i String, PathBuf)>)> {
+        let file_name = resolve_file_name(
+            item_config.file_name_rule(),
+            self.config.resolve_file_name_case(category, item_config),
+            item_name,
+        );
+        let template_name =
+            resolve_template_name(item_type, item_config.template_name_rule(), item_name);
+
+        let mut outputs = vec![(&item_config.template, &item_config.file_extension)];
+        for file in &item_config.files {
+            let included = match &file.when {
+                Some(condition) => evaluate_condition(condition, variables)?,
+                None => true,
+            };
+            if included {
+                outputs.push((&file.template, &file.file_extension));
             }
         }
 
-        // Then check static categories
-        for category_name in self.config.get_categories() {
-            if let Some(category) = self.config.get_category(&category_name) {
-                // Skip dynamic categories (already checked)
-                if category.supports_dynamic_children() {
-                    continue;
-                }
+        let outputs = outputs
+            .into_iter()
+            .map(|(template, file_extension)| {
+                let file_path = item_path.join(format!("{}.{}", file_name, file_extension));
+                (template, file_path)
+            })
+            .collect();
 
-                // Check static items
-                if category.get_item(item_type).is_some() {
-                    return Ok((category_name, category));
-                }
+        Ok((template_name, outputs))
+    }
+
+    /// Handle render command execution: print the files a create would write,
+    /// rendered with the same context, without touching the filesystem
+    pub fn handle_render(&self, cmd: Commands) -> Result<()> {
+        let Commands::Render {
+            target,
+            name,
+            set,
+            no_tests,
+            category,
+            highlight,
+            ..
+        } = cmd
+        else {
+            return Err(anyhow!("Invalid command for render handler"));
+        };
+
+        let provided_variables = parse_assignments(&set)?;
+        let rendered = if target.ends_with(".hbs") {
+            self.render_template_file(&target, name.as_deref(), &provided_variables, no_tests)?
+        } else {
+            self.render_item(
+                &target,
+                name.as_deref(),
+                category.as_deref(),
+                &provided_variables,
+                no_tests,
+            )?
+        };
+
+        for (index, file) in rendered.iter().enumerate() {
+            if index > 0 {
+                println!();
+            }
+            println!(
+                "── {} ({}) ──",
+                file.path.display(),
+                file.template.display()
+            );
+
+            let extension = file
+                .path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.split_once('.'))
+                .map(|(_, extension)| extension)
+                .unwrap_or_default();
+            let contents = if highlight {
+                crate::highlight::highlight(&file.contents, extension)
+            } else {
+                file.contents.clone()
+            };
+            print!("{}", contents);
+            if !contents.ends_with('\n') {
+                println!();
             }
         }
 
-        // Build helpful error message with available types
-        let mut available_types = Vec::new();
-        for category_name in self.config.get_categories() {
-            if let Some(category) = self.config.get_category(&category_name) {
-                let static_items = category.get_item_names();
-                for item in static_items {
-                    available_types.push(format!("{} (in {})", item, category_name));
-                }
+        Ok(())
+    }
 
-                if category.supports_dynamic_children() {
-                    if let Some(default_structure) = category.get_default_structure() {
-                        for item in default_structure.keys() {
-                            available_types.push(format!("{} (in {})", item, category_name));
+    /// Render every file of an item, given as a create path or an item type plus `--name`
+    fn render_item(
+        &self,
+        target: &str,
+        name: Option<&str>,
+        category: Option<&str>,
+        provided_variables: &HashMap<String, String>,
+        no_tests: bool,
+    ) -> Result<Vec<RenderedFile>> {
+        let path = match name {
+            Some(name) => format!("{}/{}", target.trim_end_matches('/'), name),
+            None => target.to_string(),
+        };
+        let resolved = self.resolve_render_path(&path, category)?;
+
+        let mut variables =
+            resolve_variables(resolved.item.variables.as_ref(), provided_variables)?;
+        variables.insert(TESTS_VARIABLE.to_string(), Value::Bool(!no_tests));
+
+        let item_path = resolved
+            .item_dir(&self.source_dir)
+            .strip_prefix(&self.source_dir)
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|_| resolved.item_dir(&self.source_dir));
+        let (template_name, outputs) = self.item_outputs(
+            &item_path,
+            resolved.file_name_case_category(),
+            &resolved.item_type,
+            &resolved.item_name,
+            resolved.item,
+            &variables,
+        )?;
+
+        outputs
+            .into_iter()
+            .map(|(template, path)| {
+                let template = self.config.resolve_template_path(template);
+                let contents =
+                    self.generator()?
+                        .render(&template, template_name.clone(), &variables)?;
+                Ok(RenderedFile {
+                    path,
+                    template,
+                    contents,
+                })
+            })
+            .collect()
+    }
+
+    /// Resolve a render target like a create path. `item_type/name` is also accepted;
+    /// modules in the displayed path are then shown as `<module>`.
+    fn resolve_render_path(&self, path: &str, category: Option<&str>) -> Result<ResolvedItem<'_>> {
+        let error = match self.resolve_create_path(path, category) {
+            Ok(resolved) => return Ok(resolved),
+            Err(error) => error,
+        };
+
+        let (prefix, rest) = split_category_prefix(path);
+        let Some((item_type, item_name)) =
+            rest.split_once('/').filter(|(_, name)| !name.contains('/'))
+        else {
+            return Err(error);
+        };
+        let (category_name, category) = match prefix.or(category) {
+            Some(name) => match self.config.get_category(name) {
+                Some(category) => (name.to_string(), category),
+                None => return Err(error),
+            },
+            None => self.find_category_for_item_type(item_type)?,
+        };
+        let Some(item) = category.find_item(item_type) else {
+            return Err(error);
+        };
+
+        let in_module = category.get_item(item_type).is_none();
+        let mut directories = vec![category_name.clone()];
+        if in_module {
+            directories.push("<module>".to_string());
+        }
+        Ok(ResolvedItem {
+            category_name,
+            directories,
+            categories: vec![category],
+            modules: Vec::new(),
+            item_type: item_type.to_string(),
+            item,
+            item_name: item_name.to_string(),
+        })
+    }
+
+    /// Render a template file on its own. The template name follows the defaults
+    /// of the item type named like the file (`hooks.hbs` renders `use-x` as `X`).
+    fn render_template_file(
+        &self,
+        target: &str,
+        name: Option<&str>,
+        provided_variables: &HashMap<String, String>,
+        no_tests: bool,
+    ) -> Result<Vec<RenderedFile>> {
+        let name = name.ok_or_else(|| {
+            anyhow!("Rendering a template file needs a name.\n💡 Example: creator render {} --name cat-list", target)
+        })?;
+        let template = self.config.resolve_template_path(target);
+        if !template.is_file() {
+            return Err(anyhow!("Template '{}' not found", target));
+        }
+
+        let item_type = template
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        let mut variables: Map<String, Value> = provided_variables
+            .iter()
+            .map(|(key, value)| (key.clone(), Value::String(value.clone())))
+            .collect();
+        variables.insert(TESTS_VARIABLE.to_string(), Value::Bool(!no_tests));
+
+        let contents = self.generator()?.render(
+            &template,
+            resolve_template_name(item_type, None, name),
+            &variables,
+        )?;
+        Ok(vec![RenderedFile {
+            path: PathBuf::from(name),
+            template,
+            contents,
+        }])
+    }
+
+    /// Handle test-templates command execution: render every template fixture and
+    /// compare it with its snapshot, or record new snapshots with `--update`
+    pub fn handle_test_templates(&self, update: bool) -> Result<()> {
+        let mut templates: Vec<PathBuf> = Vec::new();
+        let found = self
+            .config
+            .templates_dir()
+            .map(|dir| find_templates(&dir))
+            .unwrap_or_default();
+        for template in self.config.declared_templates().into_iter().chain(found) {
+            if !templates.contains(&template) {
+                templates.push(template);
+            }
+        }
+
+        println!("🧪 Testing templates...");
+        let (mut total, mut failed, mut updated, mut untested) = (0, 0, 0, 0);
+        for template in &templates {
+            let cases = load_fixtures(template)?;
+            if cases.is_empty() {
+                untested += 1;
+                continue;
+            }
+
+            for case in cases {
+                total += 1;
+                let label = format!("{} › {}", template.display(), case.case);
+                let outcome = self
+                    .render_fixture(template, &case.fixture)
+                    .and_then(|actual| check_snapshot(&case, &actual, update));
+
+                match outcome {
+                    Ok(Outcome::Passed) => println!("   ✅ {}", label),
+                    Ok(Outcome::Updated) => {
+                        updated += 1;
+                        println!("   📝 {} (snapshot updated)", label);
+                    }
+                    Ok(Outcome::Missing) => {
+                        failed += 1;
+                        println!("   ❌ {} (no snapshot yet)", label);
+                    }
+                    Ok(Outcome::Failed(diff)) => {
+                        failed += 1;
+                        println!("   ❌ {}", label);
+                        for line in diff.lines() {
+                            println!("      {}", line);
                         }
                     }
+                    Err(e) => {
+                        failed += 1;
+                        println!("   ❌ {}: {}", label, e);
+                    }
                 }
             }
         }
 
+        println!();
+        println!(
+            "{} passed, {} failed, {} updated ({} template(s) without fixtures)",
+            total - failed - updated,
+            failed,
+            updated,
+            untested
+        );
+
+        if failed > 0 {
+            return Err(anyhow!(
+                "{} of {} template snapshot(s) failed.\n💡 Review the diffs, then run `creator test-templates --update` to accept the new output",
+                failed,
+                total
+            ));
+        }
+        Ok(())
+    }
+
+    /// Render a template for a fixture, the way `create` would for the fixture's item
+    fn render_fixture(&self, template: &Path, fixture: &Fixture) -> Result<String> {
+        let mut provided = fixture.variables.clone();
+        let tests = provided.remove(TESTS_VARIABLE).unwrap_or(Value::Bool(true));
+
+        let (template_name, mut variables) = match &fixture.item {
+            Some(item) => {
+                let resolved =
+                    self.resolve_render_path(&format!("{}/{}", item, fixture.name), None)?;
+                let variables = resolve_variables(
+                    resolved.item.variables.as_ref(),
+                    &variables_as_assignments(&provided),
+                )?;
+                let template_name = resolve_template_name(
+                    &resolved.item_type,
+                    resolved.item.template_name_rule(),
+                    &fixture.name,
+                );
+                (template_name, variables)
+            }
+            None => {
+                let item_type = template
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .unwrap_or_default();
+                (
+                    resolve_template_name(item_type, None, &fixture.name),
+                    provided,
+                )
+            }
+        };
+        variables.insert(TESTS_VARIABLE.to_string(), tests);
+
+        self.generator()?
+            .render(template, template_name, &variables)
+    }
+
+    /// Resolve a create path to the item it targets.
+    ///
+    /// Paths starting with a category are walked through the category tree
+    /// (`category/[subcategory|module]*/item_type/name`). Three-part paths starting
+    /// with a module name keep the shorthand `module/item_type/name`, where the
+    /// category is inferred from the item type. An explicit category, given as a
+    /// `category:` prefix or with `--category`, skips the inference.
+    fn resolve_create_path(&self, path: &str, category: Option<&str>) -> Result<ResolvedItem<'_>> {
+        let (prefix, path) = split_category_prefix(path);
+        if let Some(category_name) = explicit_category(prefix, category)? {
+            return self.resolve_in_category(category_name, path);
+        }
+
+        let parts: Vec<&str> = path.split('/').collect();
+        if parts.len() < 3 {
+            return Err(anyhow!(
+                "Invalid path format. Expected: module/item_type/name, got: {}\n💡 Example: cats/components/cat-list",
+                path
+            ));
+        }
+
+        match self.config.get_category(parts[0]) {
+            // Dynamic category name used as a module name, e.g. modules/components/x
+            Some(category) if category.supports_dynamic_children() && parts.len() == 3 => {
+                resolve_tree_path(&self.config, path)
+                    .or_else(|_| self.resolve_module_path(&parts, None))
+            }
+            Some(_) => resolve_tree_path(&self.config, path),
+            None if parts.len() == 3 => self.resolve_module_path(&parts, None),
+            None => Err(anyhow!(
+                "Invalid path format. Expected: module/item_type/name, got: {}\n💡 Example: cats/components/cat-list\n💡 Longer paths must start with a category: {}",
+                path,
+                self.config.get_categories().join(", ")
+            )),
+        }
+    }
+
+    /// Resolve a path relative to an explicitly selected category
+    fn resolve_in_category(&self, category_name: &str, path: &str) -> Result<ResolvedItem<'_>> {
+        let category = self
+            .config
+            .get_category(category_name)
+            .ok_or_else(|| self.category_not_found(category_name))?;
+
+        let parts: Vec<&str> = path.split('/').collect();
+        resolve_tree_path(&self.config, &format!("{}/{}", category_name, path)).or_else(|error| {
+            // Static categories also accept the module/item_type/name shorthand
+            if parts.len() == 3 && !category.supports_dynamic_children() {
+                self.resolve_module_path(&parts, Some(category_name))
+                    .map_err(|_| error)
+            } else {
+                Err(error)
+            }
+        })
+    }
+
+    /// Resolve the shorthand `module/item_type/name`, looking the item type up
+    /// unless the category is given
+    fn resolve_module_path(
+        &self,
+        parts: &[&str],
+        category_name: Option<&str>,
+    ) -> Result<ResolvedItem<'_>> {
+        let (module_name, item_type, item_name) = (parts[0], parts[1], parts[2]);
+        let (category_name, category) = match category_name {
+            Some(name) => (
+                name.to_string(),
+                self.config
+                    .get_category(name)
+                    .ok_or_else(|| self.category_not_found(name))?,
+            ),
+            None => self.find_category_for_item_type(item_type)?,
+        };
+        let item = category.find_item(item_type).ok_or_else(|| {
+            let item_types = category_item_types(category);
+            anyhow!(
+                "Item type '{}' not found in category '{}'.\n{}💡 Available types: {}",
+                item_type,
+                category_name,
+                did_you_mean(item_type, item_types.iter().map(String::as_str)),
+                item_types.join(", ")
+            )
+        })?;
+
+        let (directories, modules) = if category.supports_dynamic_children() {
+            (
+                vec![category_name.clone(), to_kebab_case(module_name)],
+                vec![module_name.to_string()],
+            )
+        } else {
+            // The module name is not part of static paths, but it must still be valid
+            validate_name("module", module_name, &self.config.module_validation(), &[])
+                .map_err(|message| anyhow!(message))?;
+            (vec![category_name.clone()], Vec::new())
+        };
+
+        Ok(ResolvedItem {
+            category_name,
+            directories,
+            categories: vec![category],
+            modules,
+            item_type: item_type.to_string(),
+            item,
+            item_name: item_name.to_string(),
+        })
+    }
+
+    /// Directory hooks and git run in: the project root for workspace projects,
+    /// otherwise the current directory
+    fn project_dir(&self) -> PathBuf {
+        self.config
+            .base_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("."))
+    }
+
+    /// A generated file's path as seen from `project_dir`: relative to the project
+    /// root when it's inside it, otherwise absolute
+    fn project_path(&self, path: &Path) -> PathBuf {
+        match &self.config.base_dir {
+            None => path.to_path_buf(),
+            Some(base_dir) => match path.strip_prefix(base_dir) {
+                Ok(relative) => relative.to_path_buf(),
+                Err(_) => std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()),
+            },
+        }
+    }
+
+    /// Open files with the configured editor, then `$VISUAL` or `$EDITOR`
+    fn open_in_editor(&self, files: &[PathBuf]) -> Result<()> {
+        if files.is_empty() {
+            return Ok(());
+        }
+
+        let editor = self
+            .config
+            .preferences()
+            .editor
+            .or_else(|| std::env::var("VISUAL").ok())
+            .or_else(|| std::env::var("EDITOR").ok())
+            .filter(|editor| !editor.trim().is_empty())
+            .ok_or_else(|| {
+                anyhow!(
+                    "No editor configured. Set preferences.editor in your user config or $EDITOR"
+                )
+            })?;
+
+        // The editor may carry arguments, e.g. "code --reuse-window"
+        let mut parts = editor.split_whitespace();
+        let program = parts.next().unwrap_or_default();
+        let status = std::process::Command::new(program)
+            .args(parts)
+            .args(files)
+            .status()
+            .map_err(|e| anyhow!("Failed to start editor '{}': {}", editor, e))?;
+
+        if !status.success() {
+            return Err(anyhow!("Editor '{}' exited with {}", editor, status));
+        }
+
+        Ok(())
+    }
+
+    /// Error for an unknown category, suggesting the closest one
+    fn category_not_found(&self, category_name: &str) -> anyhow::Error {
+        let categories = self.config.get_categories();
+        anyhow!(
+            "Category '{}' not found.\n{}💡 Available categories: {}",
+            category_name,
+            did_you_mean(category_name, categories.iter().map(String::as_str)),
+            categories.join(", ")
+        )
+    }
+
+    /// Warn when a new module's name is one edit away from an existing module,
+    /// which is more often a typo than a new module
+    fn warn_about_similar_module(&self, category_name: &str, module_name: &str) {
+        let category_dir = self.source_dir.join(category_name);
+        if category_dir.join(to_kebab_case(module_name)).exists() {
+            return;
+        }
+
+        let modules = list_subdirectories(&category_dir);
+        if let Some(similar) = closest(module_name, modules.iter().map(String::as_str)) {
+            println!(
+                "⚠️  Module '{}' doesn't exist yet and will be created. 💡 Did you mean '{}'?",
+                module_name, similar
+            );
+        }
+    }
+
+    /// Names of the categories that allow modules
+    fn dynamic_categories(&self) -> Vec<String> {
+        self.config
+            .get_categories()
+            .into_iter()
+            .filter(|name| {
+                self.config
+                    .get_category(name)
+                    .is_some_and(|category| category.supports_dynamic_children())
+            })
+            .collect()
+    }
+
+    /// A category that allows modules, by name
+    fn dynamic_category(&self, category_name: &str) -> Result<&Category> {
+        let category = self
+            .config
+            .get_category(category_name)
+            .ok_or_else(|| self.category_not_found(category_name))?;
+        if !category.supports_dynamic_children() {
+            return Err(anyhow!(
+                "Category '{}' doesn't allow modules.\n💡 Categories with modules: {}",
+                category_name,
+                self.dynamic_categories().join(", ")
+            ));
+        }
+        Ok(category)
+    }
+
+    /// Discover existing module folders under every dynamic category
+    fn existing_modules(&self) -> Vec<String> {
+        let mut modules: Vec<String> = self
+            .config
+            .get_categories()
+            .iter()
+            .filter(|name| {
+                self.config
+                    .get_category(name)
+                    .is_some_and(|category| category.supports_dynamic_children())
+            })
+            .flat_map(|name| list_subdirectories(&self.source_dir.join(name)))
+            .collect();
+        modules.sort();
+        modules.dedup();
+        modules
+    }
+
+    /// Find the category that contains the specified item type.
+    /// Fails with the candidate list when several categories match.
+    fn find_category_for_item_type(
+        &self,
+        item_type: &str,
+    ) -> Result<(String, &crate::config::Category)> {
+        let categories: Vec<(String, &Category)> = self
+            .config
+            .get_categories()
+            .into_iter()
+            .filter_map(|name| {
+                let category = self.config.get_category(&name)?;
+                Some((name, category))
+            })
+            .collect();
+
+        // First, check dynamic categories (they have priority for cohesive modules)
+        let mut candidates: Vec<(String, &Category)> = categories
+            .iter()
+            .filter(|(_, category)| {
+                category.supports_dynamic_children()
+                    && category
+                        .get_default_structure()
+                        .is_some_and(|structure| structure.contains_key(item_type))
+            })
+            .cloned()
+            .collect();
+
+        // Then check static categories
+        if candidates.is_empty() {
+            candidates = categories
+                .iter()
+                .filter(|(_, category)| {
+                    !category.supports_dynamic_children() && category.get_item(item_type).is_some()
+                })
+                .cloned()
+                .collect();
+        }
+
+        if candidates.len() == 1 {
+            return Ok(candidates.remove(0));
+        }
+
+        if !candidates.is_empty() {
+            let names: Vec<&str> = candidates.iter().map(|(name, _)| name.as_str()).collect();
+            return Err(anyhow!(
+                "Item type '{}' is ambiguous: it exists in categories {}.\n💡 Choose one with a category prefix or --category, e.g.:\n  creator create {}:<module>/{}/<name>\n  creator create <module>/{}/<name> --category {}",
+                item_type,
+                names.join(", "),
+                names[0],
+                item_type,
+                item_type,
+                names[0]
+            ));
+        }
+
+        // Suggest the closest item type, or list them all when nothing is close
+        let mut available_types = Vec::new();
+        for (category_name, category) in &categories {
+            for item in category_item_types(category) {
+                available_types.push((item, category_name.as_str()));
+            }
+        }
+
+        let suggestion = closest(
+            item_type,
+            available_types.iter().map(|(item, _)| item.as_str()),
+        );
+        if let Some(suggestion) = suggestion {
+            let in_categories: Vec<&str> = available_types
+                .iter()
+                .filter(|(item, _)| item == suggestion)
+                .map(|(_, category_name)| *category_name)
+                .collect();
+            return Err(anyhow!(
+                "Item type '{}' not found in any module.\n💡 Did you mean '{}' (in {})?",
+                item_type,
+                suggestion,
+                in_categories.join(", ")
+            ));
+        }
+
+        let available_types: Vec<String> = available_types
+            .iter()
+            .map(|(item, category_name)| format!("{} (in {})", item, category_name))
+            .collect();
         Err(anyhow!(
             "Item type '{}' not found in any module.\n💡 Available types:\n  {}",
             item_type,
@@ -486,11 +1994,173 @@ impl CliEngine {
     }
 }
 
+/// The category selected by a `category:` prefix or `--category`, which must agree
+fn explicit_category<'a>(
+    prefix: Option<&'a str>,
+    flag: Option<&'a str>,
+) -> Result<Option<&'a str>> {
+    match (prefix, flag) {
+        (Some(prefix), Some(flag)) if prefix != flag => Err(anyhow!(
+            "Conflicting categories: path selects '{}' but --category is '{}'",
+            prefix,
+            flag
+        )),
+        (prefix, flag) => Ok(prefix.or(flag)),
+    }
+}
+
+/// Folders a new module gets, relative to its folder: one per item type, and with a
+/// module structure, its nested categories and theirs. Nested modules are left to be
+/// created on demand.
+fn module_folders(category: &Category) -> Vec<PathBuf> {
+    match category.get_module_structure() {
+        Some(module_structure) => category_folders(module_structure),
+        None => category
+            .get_default_structure()
+            .map(|items| items.keys().map(PathBuf::from).collect())
+            .unwrap_or_default(),
+    }
+}
+
+/// Item type folders of a category, then each nested category with its folders
+fn category_folders(category: &Category) -> Vec<PathBuf> {
+    let mut folders: Vec<PathBuf> = category
+        .get_item_names()
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+    for name in category.get_subcategory_names() {
+        if let Some(subcategory) = category.get_subcategory(&name) {
+            folders.push(PathBuf::from(&name));
+            folders.extend(
+                category_folders(subcategory)
+                    .into_iter()
+                    .map(|folder| Path::new(&name).join(folder)),
+            );
+        }
+    }
+    folders
+}
+
+/// Whether `dir` is a real directory directly inside `parent`, not a link or a path
+/// that leads elsewhere
+fn is_child_dir(parent: &Path, dir: &Path) -> bool {
+    let is_real_dir = fs::symlink_metadata(dir).is_ok_and(|metadata| metadata.is_dir());
+    let single_name = dir.strip_prefix(parent).is_ok_and(|relative| {
+        let mut components = relative.components();
+        matches!(components.next(), Some(std::path::Component::Normal(_)))
+            && components.next().is_none()
+    });
+    is_real_dir && single_name
+}
+
+/// Number of files under a folder, at any depth
+fn count_files(dir: &Path) -> usize {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| {
+                    let path = entry.path();
+                    if path.is_dir() {
+                        count_files(&path)
+                    } else {
+                        1
+                    }
+                })
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// Item types of a category: static children, then the dynamic default structure
+fn category_item_types(category: &Category) -> Vec<String> {
+    let mut item_types = category.get_item_names();
+    if category.supports_dynamic_children() {
+        if let Some(default_structure) = category.get_default_structure() {
+            item_types.extend(default_structure.keys().cloned());
+        }
+    }
+    item_types
+}
+
+/// Ask how to create a new config: with the wizard or from a preset
+pub fn interactive_init() -> Result<Commands> {
+    let choices = vec![
+        "Step by step (wizard)",
+        "clean-architecture preset",
+        "module-based preset",
+    ];
+    let choice = Select::new("How should the config be created?", choices)
+        .prompt()
+        .map_err(|_| anyhow!("Failed to select how to create the config"))?;
+
+    Ok(Commands::Init {
+        preset: choice.strip_suffix(" preset").map(String::from),
+        wizard: choice == "Step by step (wizard)",
+    })
+}
+
+/// `category:path` of a create command, as shown in the interactive summary
+fn create_label(command: &Commands) -> String {
+    match command {
+        Commands::Create {
+            path,
+            category: Some(category),
+            ..
+        } => format!("{}:{}", category, path),
+        Commands::Create { path, .. } => path.clone(),
+        _ => String::new(),
+    }
+}
+
+/// Whether a category declares nested categories or a per-module structure
+fn has_nested_structure(category: &Category) -> bool {
+    !category.get_subcategory_names().is_empty() || category.get_module_structure().is_some()
+}
+
+/// Describe every folder reachable from a category with the item types it accepts,
+/// e.g. `features/<module>/ui/ → components`
+fn describe_layout(category: &Category, prefix: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    let items = category.get_item_names();
+    if !items.is_empty() {
+        lines.push(format!("{}/ → {}", prefix, items.join(", ")));
+    }
+
+    if category.supports_dynamic_children() && category.get_module_structure().is_none() {
+        if let Some(default_structure) = category.get_default_structure() {
+            let types: Vec<String> = default_structure.keys().cloned().collect();
+            lines.push(format!("{}/<module>/ → {}", prefix, types.join(", ")));
+        }
+    }
+
+    for name in category.get_subcategory_names() {
+        if let Some(subcategory) = category.get_subcategory(&name) {
+            lines.extend(describe_layout(
+                subcategory,
+                &format!("{}/{}", prefix, name),
+            ));
+        }
+    }
+
+    if let Some(module_structure) = category.get_module_structure() {
+        lines.extend(describe_layout(
+            module_structure,
+            &format!("{}/<module>", prefix),
+        ));
+    }
+
+    lines
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::{Category, Item, ProjectInfo};
-    use std::collections::HashMap;
+    use crate::opts::Commands;
+    use indexmap::IndexMap;
     use tempfile::TempDir;
 
     fn create_test_engine() -> (CliEngine, TempDir) {
@@ -538,10 +2208,10 @@ mod tests {
 
     fn create_test_config_with_temp_dir(temp_dir: &std::path::Path) -> ProjectConfig {
         // Create a test config with both dynamic and static categories
-        let mut categories = HashMap::new();
+        let mut categories = IndexMap::new();
 
         // Dynamic category (modules)
-        let mut modules_default = HashMap::new();
+        let mut modules_default = IndexMap::new();
         modules_default.insert(
             "components".to_string(),
             Item {
@@ -550,6 +2220,7 @@ mod tests {
                     .to_string_lossy()
                     .to_string(),
                 file_extension: "tsx".to_string(),
+                ..Default::default()
             },
         );
         modules_default.insert(
@@ -560,6 +2231,7 @@ mod tests {
                     .to_string_lossy()
                     .to_string(),
                 file_extension: "ts".to_string(),
+                ..Default::default()
             },
         );
         modules_default.insert(
@@ -570,6 +2242,7 @@ mod tests {
                     .to_string_lossy()
                     .to_string(),
                 file_extension: "ts".to_string(),
+                ..Default::default()
             },
         );
 
@@ -580,11 +2253,12 @@ mod tests {
                 children: None,
                 allow_dynamic_children: Some(true),
                 default_structure: Some(modules_default),
+                ..Default::default()
             },
         );
 
         // Static category (pages)
-        let mut pages_children = HashMap::new();
+        let mut pages_children = IndexMap::new();
         pages_children.insert(
             "dashboard".to_string(),
             Item {
@@ -593,6 +2267,7 @@ mod tests {
                     .to_string_lossy()
                     .to_string(),
                 file_extension: "tsx".to_string(),
+                ..Default::default()
             },
         );
         pages_children.insert(
@@ -603,6 +2278,7 @@ mod tests {
                     .to_string_lossy()
                     .to_string(),
                 file_extension: "tsx".to_string(),
+                ..Default::default()
             },
         );
 
@@ -613,11 +2289,12 @@ mod tests {
                 children: Some(pages_children),
                 allow_dynamic_children: None,
                 default_structure: None,
+                ..Default::default()
             },
         );
 
         // Mixed category (features) - has both static and dynamic
-        let mut features_children = HashMap::new();
+        let mut features_children = IndexMap::new();
         features_children.insert(
             "auth".to_string(),
             Item {
@@ -626,93 +2303,540 @@ mod tests {
                     .to_string_lossy()
                     .to_string(),
                 file_extension: "ts".to_string(),
+                ..Default::default()
+            },
+        );
+
+        let mut features_default = IndexMap::new();
+        features_default.insert(
+            "components".to_string(),
+            Item {
+                template: temp_dir
+                    .join("templates/components.hbs")
+                    .to_string_lossy()
+                    .to_string(),
+                file_extension: "tsx".to_string(),
+                ..Default::default()
             },
         );
 
-        let mut features_default = HashMap::new();
-        features_default.insert(
-            "components".to_string(),
-            Item {
-                template: temp_dir
-                    .join("templates/components.hbs")
-                    .to_string_lossy()
-                    .to_string(),
-                file_extension: "tsx".to_string(),
+        categories.insert(
+            "features".to_string(),
+            Category {
+                description: Some("Mixed features".to_string()),
+                children: Some(features_children),
+                allow_dynamic_children: Some(true),
+                default_structure: Some(features_default),
+                ..Default::default()
+            },
+        );
+
+        ProjectConfig {
+            project: ProjectInfo {
+                name: "test-project".to_string(),
+                version: "1.0".to_string(),
+                structure: categories,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_find_category_for_item_type_dynamic_priority() {
+        let (engine, _temp_dir) = create_test_engine();
+
+        // Dynamic categories should have priority - "hooks" only exists in "modules"
+        let result = engine.find_category_for_item_type("hooks").unwrap();
+        assert!(result.1.supports_dynamic_children());
+        assert_eq!(result.0, "modules");
+
+        // Both "modules" and "features" have "components": ambiguous, candidates listed
+        let error_msg = engine
+            .find_category_for_item_type("components")
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("ambiguous"));
+        assert!(error_msg.contains("modules, features"));
+        assert!(error_msg.contains("--category"));
+    }
+
+    #[test]
+    fn test_find_category_for_item_type_static_fallback() {
+        let (engine, _temp_dir) = create_test_engine();
+
+        // Should find in static category when not in dynamic
+        let result = engine.find_category_for_item_type("dashboard").unwrap();
+        assert_eq!(result.0, "pages");
+        assert!(!result.1.supports_dynamic_children());
+    }
+
+    #[test]
+    fn test_find_category_for_item_type_mixed_category() {
+        let (engine, _temp_dir) = create_test_engine();
+
+        // Should find dynamic items first - services only exists in modules
+        let result = engine.find_category_for_item_type("services").unwrap();
+        assert!(result.1.supports_dynamic_children());
+
+        // Should find static items - auth only exists as static in features
+        // But the current logic prioritizes dynamic over static, so let's test what actually happens
+        let result = engine.find_category_for_item_type("auth");
+        // auth is static in features, but since the logic checks dynamic first,
+        // and features has dynamic support, it might not find auth
+        if let Ok((category_name, _)) = result {
+            assert_eq!(category_name, "features");
+        } else {
+            // This is expected due to the current implementation prioritizing dynamic
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn test_find_category_for_item_type_not_found() {
+        let (engine, _temp_dir) = create_test_engine();
+
+        let result = engine.find_category_for_item_type("nonexistent");
+        assert!(result.is_err());
+        let error_msg = result.unwrap_err().to_string();
+        assert!(error_msg.contains("not found in any module"));
+        assert!(error_msg.contains("Available types:"));
+    }
+
+    #[test]
+    fn test_typo_suggestions() {
+        let (engine, _temp_dir) = create_test_engine();
+
+        let error_msg = engine
+            .find_category_for_item_type("componets")
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Did you mean 'components' (in modules, features)?"));
+        assert!(!error_msg.contains("Available types:"));
+
+        let error_msg = engine
+            .resolve_create_path("cats/componets/cat-list", Some("modules"))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error_msg.contains("Did you mean 'components'?"),
+            "{}",
+            error_msg
+        );
+
+        let error_msg = engine
+            .resolve_create_path("cats/components/cat-list", Some("modlues"))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Category 'modlues' not found"));
+        assert!(error_msg.contains("Did you mean 'modules'?"));
+
+        let error_msg = engine
+            .handle_list(Commands::List {
+                category: Some("featurs".to_string()),
+            })
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Did you mean 'features'?"));
+    }
+
+    #[test]
+    fn test_interactive_create_targets() {
+        let (engine, temp_dir) = create_test_engine();
+        std::fs::create_dir_all(temp_dir.path().join("modules/cats")).unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("features/auth-flow")).unwrap();
+
+        let targets = engine.create_targets();
+        let labels: Vec<String> = targets.iter().map(|t| t.to_string()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "cats (modules)",
+                "auth-flow (features)",
+                "➕ New module",
+                "pages items (no module)",
+                "features items (no module)",
+            ]
+        );
+
+        // The module of the previous create is found again for the next one
+        let previous = Commands::Create {
+            path: "Cats/hooks/use-cat".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: Some("modules".to_string()),
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        assert_eq!(
+            targets.iter().position(|target| target.matches(&previous)),
+            Some(0)
+        );
+        assert_eq!(create_label(&previous), "modules:Cats/hooks/use-cat");
+
+        // Static items are created with a category-relative path
+        let static_item = Commands::Create {
+            path: "dashboard/home".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: Some("pages".to_string()),
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        assert_eq!(
+            targets
+                .iter()
+                .position(|target| target.matches(&static_item)),
+            Some(3)
+        );
+        let generated = engine.create_item(static_item).unwrap();
+        assert_eq!(generated.created.len(), 1);
+        assert!(generated.created[0].starts_with(temp_dir.path().join("pages/dashboard")));
+    }
+
+    #[test]
+    fn test_interactive_walk_through_nested_categories() {
+        let (mut engine, temp_dir) = create_test_engine();
+        let components = engine
+            .config
+            .get_category("modules")
+            .unwrap()
+            .get_default_structure()
+            .unwrap()["services"]
+            .clone();
+        let mut ui_items = IndexMap::new();
+        ui_items.insert("components".to_string(), components);
+        let mut sections = IndexMap::new();
+        sections.insert(
+            "ui".to_string(),
+            Category {
+                children: Some(ui_items),
+                ..Default::default()
+            },
+        );
+        engine.config.project.structure.insert(
+            "domains".to_string(),
+            Category {
+                allow_dynamic_children: Some(true),
+                module_structure: Some(Box::new(Category {
+                    categories: Some(sections),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+        );
+        engine.config.validate().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("domains/billing")).unwrap();
+
+        let domains = engine.config.get_category("domains").unwrap();
+        let mut level = TreeLevel::new(domains, temp_dir.path().join("domains"));
+        assert_eq!(
+            level.choices(),
+            vec![
+                TreeChoice::Module("billing".to_string()),
+                TreeChoice::NewModule
+            ]
+        );
+
+        // A module's structure offers its nested categories, then their item types
+        level.enter_module("billing");
+        assert_eq!(
+            level.choices(),
+            vec![TreeChoice::Category("ui".to_string())]
+        );
+        level.enter_category("ui");
+        assert_eq!(
+            level.choices(),
+            vec![TreeChoice::ItemType("components".to_string())]
+        );
+        assert!(level.item("components").is_some());
+        assert_eq!(level.dir, temp_dir.path().join("domains/billing/ui"));
+
+        let path = format!("{}/components/invoice-list", level.segments.join("/"));
+        assert_eq!(path, "billing/ui/components/invoice-list");
+        let generated = engine
+            .create_item(Commands::Create {
+                path,
+                set: vec![],
+                no_tests: true,
+                category: Some("domains".to_string()),
+                on_conflict: None,
+                output: None,
+                open: false,
+                no_hooks: false,
+                stage: false,
+                commit: false,
+                allow_dirty: false,
+            })
+            .unwrap();
+        assert!(
+            generated.created[0].starts_with(temp_dir.path().join("domains/billing/ui/components"))
+        );
+
+        // Modules without a module structure offer the default structure
+        let modules = engine.config.get_category("modules").unwrap();
+        let mut level = TreeLevel::new(modules, temp_dir.path().join("modules"));
+        level.enter_module("cats");
+        assert!(level
+            .choices()
+            .contains(&TreeChoice::ItemType("services".to_string())));
+        assert!(level.item("services").is_some());
+    }
+
+    #[test]
+    fn test_module_suggestions_stay_in_the_category() {
+        let (engine, temp_dir) = create_test_engine();
+        std::fs::create_dir_all(temp_dir.path().join("modules/cats")).unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("features")).unwrap();
+
+        // 'cats' only exists in modules, so a new 'cat' feature isn't questioned
+        let name = engine
+            .confirm_module_name("cat".to_string(), &temp_dir.path().join("features"))
+            .unwrap();
+        assert_eq!(name, "cat");
+        let name = engine
+            .confirm_module_name("cats".to_string(), &temp_dir.path().join("modules"))
+            .unwrap();
+        assert_eq!(name, "cats");
+    }
+
+    #[test]
+    fn test_module_scaffold() {
+        let (mut engine, temp_dir) = create_test_engine();
+        let scaffold = |engine: &CliEngine, name: &str, category: Option<&str>| {
+            engine.handle_module(Commands::Module {
+                action: ModuleAction::Scaffold {
+                    name: name.to_string(),
+                    category: category.map(String::from),
+                },
+            })
+        };
+
+        // Modules and features both allow modules
+        let error_msg = scaffold(&engine, "cats", None).unwrap_err().to_string();
+        assert!(error_msg.contains("Several categories allow modules: modules, features"));
+
+        scaffold(&engine, "modules:Cats", None).unwrap();
+        let module_dir = temp_dir.path().join("modules/cats");
+        for item_type in ["components", "services", "hooks"] {
+            assert!(module_dir.join(item_type).is_dir());
+        }
+
+        let error_msg = scaffold(&engine, "cats", Some("modules"))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Module 'cats' already exists"));
+        let error_msg = scaffold(&engine, "home", Some("pages"))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Category 'pages' doesn't allow modules"));
+        let error_msg = scaffold(&engine, "bad name", Some("modules"))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Invalid module name 'bad name'"));
+
+        // A module structure gets its nested categories
+        let mut ui_items = IndexMap::new();
+        ui_items.insert("components".to_string(), Item::default());
+        let mut sections = IndexMap::new();
+        sections.insert(
+            "ui".to_string(),
+            Category {
+                children: Some(ui_items),
+                ..Default::default()
             },
         );
-
-        categories.insert(
-            "features".to_string(),
+        engine.config.project.structure.insert(
+            "domains".to_string(),
             Category {
-                description: Some("Mixed features".to_string()),
-                children: Some(features_children),
                 allow_dynamic_children: Some(true),
-                default_structure: Some(features_default),
+                module_structure: Some(Box::new(Category {
+                    categories: Some(sections),
+                    ..Default::default()
+                })),
+                ..Default::default()
             },
         );
-
-        ProjectConfig {
-            project: ProjectInfo {
-                name: "test-project".to_string(),
-                version: "1.0".to_string(),
-                structure: categories,
-            },
-        }
+        let folders = engine.scaffold_module("billing", Some("domains")).unwrap();
+        let billing = temp_dir.path().join("domains/billing");
+        assert_eq!(
+            folders,
+            vec![
+                billing.clone(),
+                billing.join("ui"),
+                billing.join("ui/components")
+            ]
+        );
+        assert!(billing.join("ui/components").is_dir());
     }
 
     #[test]
-    fn test_find_category_for_item_type_dynamic_priority() {
-        let (engine, _temp_dir) = create_test_engine();
+    fn test_module_remove() {
+        let (engine, temp_dir) = create_test_engine();
+        let remove = |name: &str, category: Option<&str>| {
+            engine.handle_module(Commands::Module {
+                action: ModuleAction::Remove {
+                    name: name.to_string(),
+                    category: category.map(String::from),
+                    yes: true,
+                },
+            })
+        };
+        std::fs::create_dir_all(temp_dir.path().join("modules/cats/services")).unwrap();
+        std::fs::write(temp_dir.path().join("modules/cats/services/api.ts"), "").unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("features/cats")).unwrap();
 
-        // Dynamic categories should have priority - both "modules" and "features" have "components"
-        // but the important thing is that it finds it in a dynamic category
-        let result = engine.find_category_for_item_type("components").unwrap();
-        assert!(result.1.supports_dynamic_children());
+        let error_msg = remove("cats", None).unwrap_err().to_string();
+        assert!(error_msg.contains("Module 'cats' exists in several categories: modules, features"));
 
-        // Should find in either "modules" or "features", both are dynamic
-        assert!(result.0 == "modules" || result.0 == "features");
-    }
+        remove("modules:cats", None).unwrap();
+        assert!(!temp_dir.path().join("modules/cats").exists());
+        assert!(temp_dir.path().join("features/cats").exists());
 
-    #[test]
-    fn test_find_category_for_item_type_static_fallback() {
-        let (engine, _temp_dir) = create_test_engine();
+        let error_msg = remove("catz", None).unwrap_err().to_string();
+        assert!(error_msg.contains("Module 'catz' not found in modules, features"));
+        assert!(error_msg.contains("Did you mean 'cats'?"));
 
-        // Should find in static category when not in dynamic
-        let result = engine.find_category_for_item_type("dashboard").unwrap();
-        assert_eq!(result.0, "pages");
-        assert!(!result.1.supports_dynamic_children());
+        remove("cats", None).unwrap();
+        assert!(!temp_dir.path().join("features/cats").exists());
     }
 
     #[test]
-    fn test_find_category_for_item_type_mixed_category() {
-        let (engine, _temp_dir) = create_test_engine();
-
-        // Should find dynamic items first - components exists in both modules and features
-        let result = engine.find_category_for_item_type("components").unwrap();
-        assert!(result.1.supports_dynamic_children());
-
-        // Should find static items - auth only exists as static in features
-        // But the current logic prioritizes dynamic over static, so let's test what actually happens
-        let result = engine.find_category_for_item_type("auth");
-        // auth is static in features, but since the logic checks dynamic first,
-        // and features has dynamic support, it might not find auth
-        if result.is_ok() {
-            assert_eq!(result.unwrap().0, "features");
-        } else {
-            // This is expected due to the current implementation prioritizing dynamic
-            assert!(result.is_err());
+    fn test_module_remove_rejects_paths_outside_modules() {
+        let (engine, temp_dir) = create_test_engine();
+        std::fs::create_dir_all(temp_dir.path().join("modules/cats/services")).unwrap();
+        std::fs::write(temp_dir.path().join("modules/cats/services/api.ts"), "").unwrap();
+
+        for name in ["..", ".", "", "modules:..", "cats/.."] {
+            let result = engine.handle_module(Commands::Module {
+                action: ModuleAction::Remove {
+                    name: name.to_string(),
+                    category: Some("modules".to_string()),
+                    yes: true,
+                },
+            });
+            let error_msg = result.unwrap_err().to_string();
+            assert!(error_msg.contains("Invalid module name"), "{}", error_msg);
         }
+        assert!(temp_dir
+            .path()
+            .join("modules/cats/services/api.ts")
+            .exists());
+
+        assert!(is_child_dir(
+            &temp_dir.path().join("modules"),
+            &temp_dir.path().join("modules/cats")
+        ));
+        assert!(!is_child_dir(
+            &temp_dir.path().join("modules"),
+            &temp_dir.path().join("modules/..")
+        ));
+        assert!(!is_child_dir(
+            &temp_dir.path().join("modules"),
+            &temp_dir.path().join("modules/cats/services")
+        ));
     }
 
     #[test]
-    fn test_find_category_for_item_type_not_found() {
-        let (engine, _temp_dir) = create_test_engine();
+    fn test_render_never_writes_files() {
+        let (engine, temp_dir) = create_test_engine();
+        let no_variables = HashMap::new();
+
+        let rendered = engine
+            .render_item("cats/hooks", Some("use-cats"), None, &no_variables, false)
+            .unwrap();
+        assert_eq!(rendered.len(), 1);
+        assert_eq!(
+            rendered[0].path,
+            PathBuf::from("modules/cats/hooks/use-cats.ts")
+        );
+        assert!(rendered[0].contents.contains("export function useCats()"));
+
+        // An item type alone renders without a module
+        let rendered = engine
+            .render_item("services", Some("cat"), None, &no_variables, false)
+            .unwrap();
+        assert_eq!(
+            rendered[0].path,
+            PathBuf::from("modules/<module>/services/cat.ts")
+        );
+        let rendered = engine
+            .render_item("dashboard/home", None, Some("pages"), &no_variables, false)
+            .unwrap();
+        assert_eq!(rendered[0].path, PathBuf::from("pages/dashboard/home.tsx"));
+
+        let template = temp_dir.path().join("templates/hooks.hbs");
+        let rendered = engine
+            .render_template_file(
+                template.to_str().unwrap(),
+                Some("use-cats"),
+                &no_variables,
+                false,
+            )
+            .unwrap();
+        assert!(rendered[0].contents.contains("export function useCats()"));
+
+        let error_msg = engine
+            .render_template_file(template.to_str().unwrap(), None, &no_variables, false)
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("needs a name"));
+
+        assert!(!temp_dir.path().join("modules").exists());
+        assert!(!temp_dir.path().join("pages").exists());
+    }
 
-        let result = engine.find_category_for_item_type("nonexistent");
-        assert!(result.is_err());
-        let error_msg = result.unwrap_err().to_string();
-        assert!(error_msg.contains("not found in any module"));
-        assert!(error_msg.contains("Available types:"));
+    #[test]
+    fn test_template_snapshots() {
+        let (engine, temp_dir) = create_test_engine();
+        let fixtures = temp_dir.path().join("templates/hooks.fixtures");
+        std::fs::create_dir_all(&fixtures).unwrap();
+        std::fs::write(
+            fixtures.join("basic.json"),
+            r#"{ "name": "use-cats", "item": "modules:hooks" }"#,
+        )
+        .unwrap();
+
+        // A fixture without a snapshot fails until one is recorded
+        assert!(engine.handle_test_templates(false).is_err());
+        engine.handle_test_templates(true).unwrap();
+        let snapshot = std::fs::read_to_string(fixtures.join("basic.snap")).unwrap();
+        assert!(snapshot.contains("export function useCats()"));
+        engine.handle_test_templates(false).unwrap();
+
+        // Changing the template breaks the snapshot on the next run; templates are
+        // compiled once per engine
+        std::fs::write(
+            temp_dir.path().join("templates/hooks.hbs"),
+            "export const use{{templateName}} = () => {};",
+        )
+        .unwrap();
+        let engine = CliEngine::new(engine.config.clone(), engine.source_dir.clone());
+        let error_msg = engine.handle_test_templates(false).unwrap_err().to_string();
+        assert!(error_msg.contains("1 of 1 template snapshot(s) failed"));
+
+        // Undeclared variables are rejected like `--set` would
+        std::fs::write(
+            fixtures.join("basic.json"),
+            r#"{ "name": "use-cats", "item": "modules:hooks", "variables": { "unknown": 1 } }"#,
+        )
+        .unwrap();
+        assert!(engine
+            .render_fixture(
+                &temp_dir.path().join("templates/hooks.hbs"),
+                &load_fixtures(&temp_dir.path().join("templates/hooks.hbs")).unwrap()[0].fixture,
+            )
+            .is_err());
     }
 
     #[test]
@@ -723,6 +2847,16 @@ mod tests {
         // Test static category format: category/item_type/name
         let cmd = Commands::Create {
             path: "pages/dashboard/main-dashboard".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
         };
         let result = engine.handle_create(cmd);
         assert!(result.is_ok());
@@ -752,6 +2886,16 @@ mod tests {
         for path in invalid_paths {
             let cmd = Commands::Create {
                 path: path.to_string(),
+                set: vec![],
+                no_tests: false,
+                category: None,
+                on_conflict: None,
+                output: None,
+                open: false,
+                no_hooks: false,
+                stage: false,
+                commit: false,
+                allow_dirty: false,
             };
             let result = engine.handle_create(cmd);
             assert!(result.is_err());
@@ -775,7 +2919,17 @@ mod tests {
 
         for invalid_name in invalid_module_names {
             let cmd = Commands::Create {
-                path: format!("{}/components/test", invalid_name),
+                path: format!("{}/services/test", invalid_name),
+                set: vec![],
+                no_tests: false,
+                category: None,
+                on_conflict: None,
+                output: None,
+                open: false,
+                no_hooks: false,
+                stage: false,
+                commit: false,
+                allow_dirty: false,
             };
             let result = engine.handle_create(cmd);
             assert!(result.is_err());
@@ -793,7 +2947,17 @@ mod tests {
 
         for invalid_name in invalid_item_names {
             let cmd = Commands::Create {
-                path: format!("users/components/{}", invalid_name),
+                path: format!("users/services/{}", invalid_name),
+                set: vec![],
+                no_tests: false,
+                category: None,
+                on_conflict: None,
+                output: None,
+                open: false,
+                no_hooks: false,
+                stage: false,
+                commit: false,
+                allow_dirty: false,
             };
             let result = engine.handle_create(cmd);
             assert!(result.is_err());
@@ -809,6 +2973,16 @@ mod tests {
 
         let cmd = Commands::Create {
             path: "users/unknown-type/test".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
         };
         let result = engine.handle_create(cmd);
         assert!(result.is_err());
@@ -833,7 +3007,9 @@ mod tests {
                     .to_string_lossy()
                     .to_string(),
                 file_extension: "tsx".to_string(),
+                ..Default::default()
             },
+            &Map::new(),
         );
 
         assert!(result.is_ok());
@@ -861,7 +3037,9 @@ mod tests {
             &Item {
                 template: "templates/components.hbs".to_string(),
                 file_extension: "tsx".to_string(),
+                ..Default::default()
             },
+            &Map::new(),
         );
         assert!(result.is_ok());
 
@@ -896,7 +3074,9 @@ mod tests {
                 &Item {
                     template: "templates/components.hbs".to_string(),
                     file_extension: "tsx".to_string(),
+                    ..Default::default()
                 },
+                &Map::new(),
             );
             assert!(result.is_ok());
 
@@ -922,6 +3102,16 @@ mod tests {
         // Test complete workflow: parse -> validate -> create
         let cmd = Commands::Create {
             path: "user-management/services/auth-service".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
         };
         let result = engine.handle_create(cmd);
         assert!(result.is_ok());
@@ -940,6 +3130,848 @@ mod tests {
         assert!(content.contains("AuthServiceService")); // services get "Service" suffix
     }
 
+    #[test]
+    fn test_create_uses_item_naming_rules() {
+        use crate::config::{CaseStyle, ItemNaming, NameRule};
+        let (engine, temp_dir) = create_test_engine_with_prefix("item_naming");
+
+        let item = Item {
+            template: temp_dir
+                .path()
+                .join("templates/default.hbs")
+                .to_string_lossy()
+                .to_string(),
+            file_extension: "ts".to_string(),
+            naming: Some(ItemNaming {
+                template_name: Some(NameRule {
+                    suffix: Some("Repository".to_string()),
+                    ..Default::default()
+                }),
+                file_name: Some(NameRule {
+                    case: Some(CaseStyle::Camel),
+                    suffix: Some(".repository".to_string()),
+                    ..Default::default()
+                }),
+            }),
+            ..Default::default()
+        };
+
+        engine
+            .create_cohesive_module_item(
+                "modules",
+                "cats",
+                "repositories",
+                "cat-list",
+                &item,
+                &Map::new(),
+            )
+            .unwrap();
+
+        let expected_path = temp_dir
+            .path()
+            .join("modules")
+            .join("cats")
+            .join("repositories")
+            .join("catList.repository.ts");
+        let content = std::fs::read_to_string(expected_path).unwrap();
+        assert!(content.contains("export function CatListRepository()"));
+    }
+
+    #[test]
+    fn test_create_uses_file_name_case_policy() {
+        use crate::config::{CaseStyle, FileNameCase};
+        use crate::opts::Commands;
+        let (mut engine, temp_dir) = create_test_engine_with_prefix("file_name_case");
+
+        engine.config.project.file_name_case = Some(FileNameCase::Style(CaseStyle::Pascal));
+        if let Some(modules) = engine.config.project.structure.get_mut("modules") {
+            if let Some(hooks) = modules
+                .default_structure
+                .as_mut()
+                .and_then(|items| items.get_mut("hooks"))
+            {
+                hooks.file_name_case = Some(FileNameCase::Pattern {
+                    pattern: "use{pascal}".to_string(),
+                });
+            }
+        }
+
+        for path in ["cats/services/cat-list", "cats/hooks/cat-list"] {
+            let cmd = Commands::Create {
+                path: path.to_string(),
+                set: vec![],
+                no_tests: false,
+                category: None,
+                on_conflict: None,
+                output: None,
+                open: false,
+                no_hooks: false,
+                stage: false,
+                commit: false,
+                allow_dirty: false,
+            };
+            engine.handle_create(cmd).unwrap();
+        }
+
+        let module_path = temp_dir.path().join("modules").join("cats");
+        assert!(module_path.join("services").join("CatList.ts").exists());
+        assert!(module_path.join("hooks").join("useCatList.ts").exists());
+    }
+
+    #[test]
+    fn test_handle_create_applies_configured_validation() {
+        use crate::config::{NameValidation, ProjectValidation};
+        use crate::opts::Commands;
+        let (mut engine, temp_dir) = create_test_engine_with_prefix("validation_rules");
+
+        engine.config.project.validation = Some(ProjectValidation {
+            module: None,
+            item: Some(NameValidation {
+                reserved: vec!["index".to_string()],
+                forbid_existing_modules: Some(true),
+                ..Default::default()
+            }),
+        });
+        if let Some(hooks) = engine
+            .config
+            .project
+            .structure
+            .get_mut("modules")
+            .and_then(|modules| modules.default_structure.as_mut())
+            .and_then(|items| items.get_mut("hooks"))
+        {
+            hooks.validation = Some(NameValidation {
+                required_prefix: Some("use".to_string()),
+                ..Default::default()
+            });
+        }
+        std::fs::create_dir_all(temp_dir.path().join("modules").join("dogs")).unwrap();
+
+        let cases = vec![
+            ("cats/hooks/cat-list", "rule: required_prefix"),
+            ("cats/services/index", "rule: reserved"),
+            ("cats/services/dogs", "rule: forbid_existing_modules"),
+        ];
+
+        for (path, expected_rule) in cases {
+            let cmd = Commands::Create {
+                path: path.to_string(),
+                set: vec![],
+                no_tests: false,
+                category: None,
+                on_conflict: None,
+                output: None,
+                open: false,
+                no_hooks: false,
+                stage: false,
+                commit: false,
+                allow_dirty: false,
+            };
+            let error_msg = engine.handle_create(cmd).unwrap_err().to_string();
+            assert!(
+                error_msg.contains("Invalid item name") && error_msg.contains(expected_rule),
+                "Unexpected error for '{}': {}",
+                path,
+                error_msg
+            );
+        }
+
+        let cmd = Commands::Create {
+            path: "cats/hooks/use-cat-list".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        assert!(engine.handle_create(cmd).is_ok());
+    }
+
+    #[test]
+    fn test_create_with_shared_templates_dir() {
+        use crate::opts::Commands;
+        let (mut engine, temp_dir) = create_test_engine_with_prefix("templates_dir");
+
+        let shared_dir = temp_dir.path().join("shared");
+        std::fs::create_dir_all(&shared_dir).unwrap();
+        std::fs::write(shared_dir.join("license.hbs"), "// MIT License").unwrap();
+        std::fs::write(
+            shared_dir.join("service.hbs"),
+            "{{> license}}\nexport class {{templateName}} {}",
+        )
+        .unwrap();
+
+        engine.config.project.templates_dir = Some(shared_dir.to_string_lossy().to_string());
+        if let Some(services) = engine
+            .config
+            .project
+            .structure
+            .get_mut("modules")
+            .and_then(|modules| modules.default_structure.as_mut())
+            .and_then(|items| items.get_mut("services"))
+        {
+            services.template = "service.hbs".to_string();
+        }
+
+        for path in ["cats/services/cat-api", "dogs/services/dog-api"] {
+            let cmd = Commands::Create {
+                path: path.to_string(),
+                set: vec![],
+                no_tests: false,
+                category: None,
+                on_conflict: None,
+                output: None,
+                open: false,
+                no_hooks: false,
+                stage: false,
+                commit: false,
+                allow_dirty: false,
+            };
+            engine.handle_create(cmd).unwrap();
+        }
+
+        let content =
+            std::fs::read_to_string(temp_dir.path().join("modules/dogs/services/dog-api.ts"))
+                .unwrap();
+        assert!(content.starts_with("// MIT License"));
+        assert!(content.contains("export class DogApiService {}"));
+    }
+
+    #[test]
+    fn test_handle_create_with_variables() {
+        use crate::config::{Variable, VariableType};
+        use crate::opts::Commands;
+        let (mut engine, temp_dir) = create_test_engine_with_prefix("variables");
+
+        std::fs::write(
+            temp_dir.path().join("templates/screen.hbs"),
+            "export const {{templateName}} = '{{routeName}}'; // {{stateLib}}",
+        )
+        .unwrap();
+
+        let mut variables = IndexMap::new();
+        variables.insert(
+            "routeName".to_string(),
+            Variable {
+                var_type: VariableType::String,
+                default: Some(Value::String("Home".to_string())),
+                description: None,
+                options: Vec::new(),
+            },
+        );
+        variables.insert(
+            "stateLib".to_string(),
+            Variable {
+                var_type: VariableType::Enum,
+                default: None,
+                description: None,
+                options: vec!["redux".to_string(), "zustand".to_string()],
+            },
+        );
+        if let Some(services) = engine
+            .config
+            .project
+            .structure
+            .get_mut("modules")
+            .and_then(|modules| modules.default_structure.as_mut())
+            .and_then(|items| items.get_mut("services"))
+        {
+            services.template = temp_dir
+                .path()
+                .join("templates/screen.hbs")
+                .to_string_lossy()
+                .to_string();
+            services.variables = Some(variables);
+        }
+
+        let cmd = Commands::Create {
+            path: "cats/services/cat-route".to_string(),
+            set: vec!["stateLib=zustand".to_string()],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        engine.handle_create(cmd).unwrap();
+
+        let content =
+            std::fs::read_to_string(temp_dir.path().join("modules/cats/services/cat-route.ts"))
+                .unwrap();
+        assert_eq!(content, "export const CatRouteService = 'Home'; // zustand");
+
+        // Unknown variables are rejected
+        let cmd = Commands::Create {
+            path: "cats/services/cat-route".to_string(),
+            set: vec!["unknown=1".to_string()],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        let error_msg = engine.handle_create(cmd).unwrap_err().to_string();
+        assert!(error_msg.contains("Unknown variable(s): unknown"));
+    }
+
+    #[test]
+    fn test_handle_create_with_conditional_files() {
+        use crate::config::{ItemFile, Variable, VariableType};
+        use crate::opts::Commands;
+        let (mut engine, temp_dir) = create_test_engine_with_prefix("conditional_files");
+
+        let templates = temp_dir.path().join("templates");
+        std::fs::write(templates.join("test.hbs"), "test('{{templateName}}')").unwrap();
+        std::fs::write(templates.join("styles.hbs"), "export const styles = {};").unwrap();
+
+        let mut variables = IndexMap::new();
+        variables.insert(
+            "withStyles".to_string(),
+            Variable {
+                var_type: VariableType::Bool,
+                default: Some(Value::Bool(false)),
+                description: None,
+                options: Vec::new(),
+            },
+        );
+        if let Some(dashboard) = engine
+            .config
+            .project
+            .structure
+            .get_mut("pages")
+            .and_then(|pages| pages.children.as_mut())
+            .and_then(|items| items.get_mut("dashboard"))
+        {
+            dashboard.variables = Some(variables);
+            dashboard.files = vec![
+                ItemFile {
+                    template: templates.join("test.hbs").to_string_lossy().to_string(),
+                    file_extension: "test.tsx".to_string(),
+                    when: Some("tests".to_string()),
+                },
+                ItemFile {
+                    template: templates.join("styles.hbs").to_string_lossy().to_string(),
+                    file_extension: "styles.ts".to_string(),
+                    when: Some("withStyles".to_string()),
+                },
+            ];
+        }
+
+        let dashboard_dir = temp_dir.path().join("pages").join("dashboard");
+
+        // Defaults: tests included, styles skipped
+        let cmd = Commands::Create {
+            path: "pages/dashboard/main".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        engine.handle_create(cmd).unwrap();
+        assert!(dashboard_dir.join("main.tsx").exists());
+        assert_eq!(
+            std::fs::read_to_string(dashboard_dir.join("main.test.tsx")).unwrap(),
+            "test('Main')"
+        );
+        assert!(!dashboard_dir.join("main.styles.ts").exists());
+
+        // --no-tests and withStyles=true flip both conditions
+        let cmd = Commands::Create {
+            path: "pages/dashboard/stats".to_string(),
+            set: vec!["withStyles=true".to_string()],
+            no_tests: true,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        engine.handle_create(cmd).unwrap();
+        assert!(dashboard_dir.join("stats.tsx").exists());
+        assert!(!dashboard_dir.join("stats.test.tsx").exists());
+        assert!(dashboard_dir.join("stats.styles.ts").exists());
+
+        // A broken extra template fails the create without leaving the main file behind
+        std::fs::write(templates.join("test.hbs"), "test('{{templateName}')").unwrap();
+        let engine = CliEngine::new(engine.config.clone(), engine.source_dir.clone());
+        let cmd = Commands::Create {
+            path: "pages/dashboard/broken".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        assert!(engine.handle_create(cmd).is_err());
+        assert!(!dashboard_dir.join("broken.tsx").exists());
+        assert!(!dashboard_dir.join("broken.test.tsx").exists());
+    }
+
+    #[test]
+    fn test_handle_create_with_explicit_category() {
+        use crate::opts::Commands;
+        let (engine, temp_dir) = create_test_engine_with_prefix("explicit_category");
+
+        // "components" exists in both modules and features
+        let cmd = Commands::Create {
+            path: "cats/components/cat-list".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        let error_msg = engine.handle_create(cmd).unwrap_err().to_string();
+        assert!(error_msg.contains("Item type 'components' is ambiguous"));
+
+        // Category prefix
+        let cmd = Commands::Create {
+            path: "modules:cats/components/cat-list".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        engine.handle_create(cmd).unwrap();
+        assert!(temp_dir
+            .path()
+            .join("modules/cats/components/cat-list.tsx")
+            .exists());
+
+        // --category flag
+        let cmd = Commands::Create {
+            path: "cats/components/cat-list".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: Some("features".to_string()),
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        engine.handle_create(cmd).unwrap();
+        assert!(temp_dir
+            .path()
+            .join("features/cats/components/cat-list.tsx")
+            .exists());
+
+        // Static category with the module/item_type/name shorthand
+        let cmd = Commands::Create {
+            path: "pages:users/dashboard/main".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        engine.handle_create(cmd).unwrap();
+        assert!(temp_dir.path().join("pages/dashboard/main.tsx").exists());
+
+        // Prefix and flag must agree, and the category must exist
+        let cmd = Commands::Create {
+            path: "modules:cats/components/cat-list".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: Some("features".to_string()),
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        let error_msg = engine.handle_create(cmd).unwrap_err().to_string();
+        assert!(error_msg.contains("Conflicting categories"));
+
+        let cmd = Commands::Create {
+            path: "widgets:cats/components/cat-list".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        let error_msg = engine.handle_create(cmd).unwrap_err().to_string();
+        assert!(error_msg.contains("Category 'widgets' not found"));
+    }
+
+    #[test]
+    fn test_handle_create_conflict_policy() {
+        use crate::config::Preferences;
+        use crate::opts::Commands;
+        let (mut engine, temp_dir) = create_test_engine_with_prefix("conflict_policy");
+
+        let create = || Commands::Create {
+            path: "pages/dashboard/main".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        let file_path = temp_dir.path().join("pages/dashboard/main.tsx");
+
+        engine.handle_create(create()).unwrap();
+        std::fs::write(&file_path, "edited").unwrap();
+
+        // Skip leaves the existing file alone
+        engine.config.preferences = Some(Preferences {
+            on_conflict: Some(ConflictPolicy::Skip),
+            ..Default::default()
+        });
+        engine.handle_create(create()).unwrap();
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "edited");
+
+        // Error refuses to write
+        engine.config.preferences = Some(Preferences {
+            on_conflict: Some(ConflictPolicy::Error),
+            ..Default::default()
+        });
+        let error_msg = engine.handle_create(create()).unwrap_err().to_string();
+        assert!(error_msg.contains("already exists"));
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "edited");
+
+        // Overwrite is the default
+        engine.config.preferences = None;
+        engine.handle_create(create()).unwrap();
+        assert_ne!(std::fs::read_to_string(&file_path).unwrap(), "edited");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_handle_create_runs_hooks() {
+        use crate::config::{HookFailure, Hooks};
+        use crate::opts::Commands;
+        let (mut engine, temp_dir) = create_test_engine_with_prefix("hooks");
+        engine.config.base_dir = Some(temp_dir.path().to_path_buf());
+
+        let create = |path: &str, no_hooks: bool| Commands::Create {
+            path: path.to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        let file_path = temp_dir.path().join("pages/dashboard/main.tsx");
+        let log_path = temp_dir.path().join("hooks.log");
+
+        // Hooks run in the project root with the files filled in
+        engine.config.project.hooks = Some(Hooks {
+            pre_generate: vec!["echo pre {name} {files} >> hooks.log".to_string()],
+            post_generate: vec!["echo post {module} >> hooks.log".to_string()],
+            ..Default::default()
+        });
+        engine
+            .handle_create(create("pages/dashboard/main", false))
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&log_path).unwrap(),
+            "pre main pages/dashboard/main.tsx\npost pages\n"
+        );
+
+        // A failing pre_generate hook cancels before anything is written
+        engine.config.project.hooks = Some(Hooks {
+            pre_generate: vec!["exit 1".to_string()],
+            ..Default::default()
+        });
+        let error_msg = engine
+            .handle_create(create("pages/dashboard/other", false))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Create cancelled: pre_generate hook 'exit 1'"));
+        assert!(!temp_dir.path().join("pages/dashboard/other.tsx").exists());
+
+        // --no-hooks skips them
+        engine
+            .handle_create(create("pages/dashboard/other", true))
+            .unwrap();
+        assert!(temp_dir.path().join("pages/dashboard/other.tsx").exists());
+
+        // An aborting post_generate hook restores overwritten files and removes new ones
+        std::fs::write(&file_path, "edited").unwrap();
+        engine.config.project.hooks = Some(Hooks {
+            post_generate: vec!["exit 1".to_string()],
+            on_failure: Some(HookFailure::Abort),
+            ..Default::default()
+        });
+        let error_msg = engine
+            .handle_create(create("pages/dashboard/main", false))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Rolled back 1 file(s)"));
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "edited");
+
+        let error_msg = engine
+            .handle_create(create("cats/services/cat-api", false))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Rolled back"));
+        assert!(!temp_dir.path().join("modules/cats").exists());
+
+        // With the default warn policy the files stay
+        engine.config.project.hooks = Some(Hooks {
+            post_generate: vec!["exit 1".to_string()],
+            ..Default::default()
+        });
+        engine
+            .handle_create(create("pages/dashboard/main", false))
+            .unwrap();
+        assert_ne!(std::fs::read_to_string(&file_path).unwrap(), "edited");
+    }
+
+    #[test]
+    fn test_handle_create_with_git() {
+        use crate::config::GitOptions;
+        use crate::opts::Commands;
+        use std::process::Command;
+        let (mut engine, temp_dir) = create_test_engine_with_prefix("git");
+        let dir = temp_dir.path();
+        engine.config.base_dir = Some(dir.to_path_buf());
+
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(dir)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {:?}", args);
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        git(&["init", "--quiet"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "commit.gpgsign", "false"]);
+        git(&["add", "templates"]);
+        git(&["commit", "--quiet", "-m", "initial"]);
+
+        let create = |path: &str, allow_dirty: bool| Commands::Create {
+            path: path.to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty,
+        };
+
+        // Created files are committed with the templated message, and only they are
+        engine.config.project.git = Some(GitOptions {
+            require_clean: Some(true),
+            commit: Some(true),
+            commit_message: Some("chore({module}): scaffold {name}".to_string()),
+            ..Default::default()
+        });
+        engine
+            .handle_create(create("pages/dashboard/main", false))
+            .unwrap();
+        assert_eq!(
+            git(&["log", "-1", "--name-only", "--format=%s"]),
+            "chore(pages): scaffold main\n\npages/dashboard/main.tsx"
+        );
+
+        // Uncommitted changes to a file about to be written stop the create
+        let file_path = dir.join("pages/dashboard/main.tsx");
+        std::fs::write(&file_path, "edited").unwrap();
+        let error_msg = engine
+            .handle_create(create("pages/dashboard/main", false))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("uncommitted changes"));
+        assert!(error_msg.contains("pages/dashboard/main.tsx"));
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "edited");
+
+        // --allow-dirty overrides the guard
+        engine.config.project.git = Some(GitOptions {
+            require_clean: Some(true),
+            stage: Some(true),
+            ..Default::default()
+        });
+        engine
+            .handle_create(create("pages/dashboard/main", true))
+            .unwrap();
+        assert_eq!(git(&["status", "--porcelain"]), "");
+
+        // Staging without committing leaves the new files in the index
+        engine
+            .handle_create(create("pages/dashboard/other", false))
+            .unwrap();
+        assert_eq!(
+            git(&["status", "--porcelain"]),
+            "A  pages/dashboard/other.tsx"
+        );
+        assert_eq!(
+            git(&["log", "-1", "--format=%s"]),
+            "chore(pages): scaffold main"
+        );
+    }
+
+    #[test]
+    fn test_handle_create_in_nested_categories() {
+        use crate::config::CaseStyle;
+        use crate::config::FileNameCase;
+        use crate::opts::Commands;
+        let (mut engine, temp_dir) = create_test_engine_with_prefix("nested_categories");
+
+        let components = Item {
+            template: temp_dir
+                .path()
+                .join("templates/components.hbs")
+                .to_string_lossy()
+                .to_string(),
+            file_extension: "tsx".to_string(),
+            ..Default::default()
+        };
+        let mut ui_items = IndexMap::new();
+        ui_items.insert("components".to_string(), components);
+        let mut sections = IndexMap::new();
+        sections.insert(
+            "ui".to_string(),
+            Category {
+                children: Some(ui_items),
+                ..Default::default()
+            },
+        );
+        engine.config.project.structure.insert(
+            "domains".to_string(),
+            Category {
+                allow_dynamic_children: Some(true),
+                module_structure: Some(Box::new(Category {
+                    allow_dynamic_children: Some(true),
+                    module_structure: Some(Box::new(Category {
+                        categories: Some(sections),
+                        file_name_case: Some(FileNameCase::Style(CaseStyle::Pascal)),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+        );
+        engine.config.validate().unwrap();
+
+        let cmd = Commands::Create {
+            path: "domains/billing/invoices/ui/components/invoice-list".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        engine.handle_create(cmd).unwrap();
+
+        let expected_path = temp_dir
+            .path()
+            .join("domains/billing/invoices/ui/components/InvoiceList.tsx");
+        assert!(expected_path.exists());
+
+        // The failing segment is named in the error
+        let cmd = Commands::Create {
+            path: "domains/billing/invoices/views/components/invoice-list".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        let error_msg = engine.handle_create(cmd).unwrap_err().to_string();
+        assert!(error_msg.contains("Segment 4 'views'"));
+        assert!(error_msg.contains("ui/ (category)"));
+
+        // Module names along the path are validated
+        let cmd = Commands::Create {
+            path: "domains/bill ing/invoices/ui/components/invoice-list".to_string(),
+            set: vec![],
+            no_tests: false,
+            category: None,
+            on_conflict: None,
+            output: None,
+            open: false,
+            no_hooks: false,
+            stage: false,
+            commit: false,
+            allow_dirty: false,
+        };
+        let error_msg = engine.handle_create(cmd).unwrap_err().to_string();
+        assert!(error_msg.contains("Invalid module name 'bill ing'"));
+    }
+
     #[test]
     fn test_handle_list_all_categories() {
         use crate::opts::Commands;
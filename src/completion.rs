@@ -0,0 +1,351 @@
+use clap::{CommandFactory, ValueEnum};
+use clap_complete::{generate, Shell};
+use std::path::Path;
+
+use crate::config::{Category, ProjectConfig};
+use crate::file_utils::{list_subdirectories, to_kebab_case};
+use crate::opts::Opts;
+use crate::path_resolver::split_category_prefix;
+
+/// Shells `creator completions` can generate scripts for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+/// Bash hook: completes `create` paths with `creator __complete`, everything else statically
+const BASH_HOOK: &str = r#"
+_creator_dynamic() {
+    local cur="${COMP_LINE:0:COMP_POINT}"
+    cur="${cur##* }"
+    local i args=()
+    for ((i = 1; i < COMP_CWORD; i++)); do
+        case "${COMP_WORDS[i]}" in
+            -c|--config|-s|--source_dir|--project)
+                args+=("${COMP_WORDS[i]}" "${COMP_WORDS[i+1]}")
+                ;;
+            create)
+                case "${COMP_WORDS[COMP_CWORD-1]}" in
+                    --category|--set|--on-conflict|--output) break ;;
+                esac
+                if [[ "$cur" != -* ]]; then
+                    compopt -o nospace
+                    local IFS=$'\n'
+                    # Bash splits words at ':', so drop the part before it from the replies
+                    COMPREPLY=($(creator "${args[@]}" __complete -- "$cur" 2>/dev/null | sed "s|^${cur%"${cur##*:}"}||"))
+                    return 0
+                fi
+                break
+                ;;
+        esac
+    done
+    _creator "$@"
+}
+complete -F _creator_dynamic -o bashdefault -o default creator
+"#;
+
+/// Zsh hook: completes `create` paths with `creator __complete`, everything else statically
+const ZSH_HOOK: &str = r#"
+_creator_dynamic() {
+    local i
+    local -a args candidates
+    for ((i = 2; i < CURRENT; i++)); do
+        case "${words[i]}" in
+            -c|--config|-s|--source_dir|--project)
+                args+=("${words[i]}" "${words[i+1]}")
+                ;;
+            create)
+                case "${words[CURRENT-1]}" in
+                    --category|--set|--on-conflict|--output) break ;;
+                esac
+                if [[ "${words[CURRENT]}" != -* ]]; then
+                    candidates=(${(f)"$(creator "${args[@]}" __complete -- "${words[CURRENT]}" 2>/dev/null)"})
+                    compadd -Q -S '' -- "${candidates[@]}"
+                    return
+                fi
+                break
+                ;;
+        esac
+    done
+    _creator "$@"
+}
+compdef _creator_dynamic creator
+"#;
+
+/// Fish hook: adds `create` path candidates from `creator __complete`
+const FISH_HOOK: &str = r#"
+function __creator_complete_create_path
+    set -l tokens (commandline -opc)
+    set -l args
+    for i in (seq 2 (count $tokens))
+        switch $tokens[$i]
+            case -c --config -s --source_dir --project
+                set -a args $tokens[$i] $tokens[(math $i + 1)]
+        end
+    end
+    creator $args __complete -- (commandline -ct) 2>/dev/null
+end
+complete -c creator -n "__fish_seen_subcommand_from create; and not __fish_prev_arg_in --category --set --on-conflict --output" -f -a "(__creator_complete_create_path)"
+"#;
+
+/// Generate the completion script for a shell: clap's static completions for commands
+/// and flags, plus a hook that asks `creator __complete` for `create` paths
+pub fn completion_script(shell: CompletionShell) -> String {
+    let (clap_shell, hook) = match shell {
+        CompletionShell::Bash => (Shell::Bash, BASH_HOOK),
+        CompletionShell::Zsh => (Shell::Zsh, ZSH_HOOK),
+        CompletionShell::Fish => (Shell::Fish, FISH_HOOK),
+    };
+
+    let mut script = Vec::new();
+    generate(clap_shell, &mut Opts::command(), "creator", &mut script);
+    String::from_utf8_lossy(&script).into_owned() + hook
+}
+
+/// Complete a partial `create` path. Candidates for modules, categories and item
+/// types end with `/`; nothing is offered for the item name.
+pub fn complete_create_path(
+    config: &ProjectConfig,
+    source_dir: &Path,
+    current: &str,
+) -> Vec<String> {
+    let (prefix, path) = split_category_prefix(current);
+    let (done, partial) = match path.rsplit_once('/') {
+        Some((done, partial)) => (done.split('/').collect::<Vec<_>>(), partial),
+        None => (Vec::new(), path),
+    };
+
+    let candidates = match (prefix, done.first()) {
+        // `category:` prefix: walk that category's tree
+        (Some(category_name), _) => {
+            let mut segments = vec![category_name];
+            segments.extend(&done);
+            tree_candidates(config, source_dir, &segments)
+        }
+        // First segment: module names and categories
+        (None, None) => {
+            let mut candidates = existing_modules(config, source_dir);
+            candidates.extend(config.get_categories());
+            candidates
+        }
+        (None, Some(first)) if config.get_category(first).is_some() => {
+            tree_candidates(config, source_dir, &done)
+        }
+        // Shorthand `module/item_type/name`
+        (None, Some(module_name)) if done.len() == 1 => {
+            module_item_types(config, source_dir, module_name)
+        }
+        (None, Some(_)) => Vec::new(),
+    };
+
+    let mut seen = Vec::new();
+    let base = &current[..current.len() - partial.len()];
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.starts_with(partial))
+        .map(|candidate| format!("{}{}/", base, candidate))
+        .filter(|candidate| {
+            let new = !seen.contains(candidate);
+            seen.push(candidate.clone());
+            new
+        })
+        .collect()
+}
+
+/// What may follow `segments` in a path starting with a category
+fn tree_candidates(config: &ProjectConfig, source_dir: &Path, segments: &[&str]) -> Vec<String> {
+    let Some(mut category) = config.get_category(segments[0]) else {
+        return Vec::new();
+    };
+    let mut dir = source_dir.join(segments[0]);
+
+    let mut index = 1;
+    while index < segments.len() {
+        let segment = segments[index];
+        if category.get_item(segment).is_some() {
+            // Item type: only the name follows
+            return Vec::new();
+        }
+
+        if let Some(subcategory) = category.get_subcategory(segment) {
+            category = subcategory;
+            dir.push(segment);
+        } else if category.supports_dynamic_children() {
+            dir.push(to_kebab_case(segment));
+            match category.get_module_structure() {
+                Some(module_structure) => category = module_structure,
+                // Flat module: its item types come next, then the name
+                None if index + 1 == segments.len() => {
+                    return category
+                        .get_default_structure()
+                        .map(|items| items.keys().cloned().collect())
+                        .unwrap_or_default();
+                }
+                None => return Vec::new(),
+            }
+        } else {
+            return Vec::new();
+        }
+        index += 1;
+    }
+
+    category_level_candidates(category, &dir)
+}
+
+/// Item types, nested categories and existing modules directly inside a category
+fn category_level_candidates(category: &Category, dir: &Path) -> Vec<String> {
+    let mut candidates = category.get_item_names();
+    candidates.extend(category.get_subcategory_names());
+    if category.supports_dynamic_children() {
+        candidates.extend(list_subdirectories(dir));
+    }
+    candidates
+}
+
+/// Module folders under every dynamic category
+fn existing_modules(config: &ProjectConfig, source_dir: &Path) -> Vec<String> {
+    config
+        .get_categories()
+        .iter()
+        .filter(|name| {
+            config
+                .get_category(name)
+                .is_some_and(|category| category.supports_dynamic_children())
+        })
+        .flat_map(|name| list_subdirectories(&source_dir.join(name)))
+        .collect()
+}
+
+/// Item types that can follow a module in the shorthand path: those of the dynamic
+/// categories that already have the module, otherwise every item type
+fn module_item_types(config: &ProjectConfig, source_dir: &Path, module_name: &str) -> Vec<String> {
+    let categories: Vec<&Category> = config
+        .get_categories()
+        .iter()
+        .filter_map(|name| config.get_category(name))
+        .collect();
+
+    let owning: Vec<String> = config
+        .get_categories()
+        .iter()
+        .filter(|name| {
+            config.get_category(name).is_some_and(|category| {
+                category.supports_dynamic_children()
+                    && source_dir
+                        .join(name)
+                        .join(to_kebab_case(module_name))
+                        .is_dir()
+            })
+        })
+        .flat_map(|name| {
+            config
+                .get_category(name)
+                .and_then(|category| category.get_default_structure())
+                .map(|items| items.keys().cloned().collect::<Vec<_>>())
+                .unwrap_or_default()
+        })
+        .collect();
+    if !owning.is_empty() {
+        return owning;
+    }
+
+    categories
+        .iter()
+        .flat_map(|category| {
+            let mut item_types = category.get_item_names();
+            if category.supports_dynamic_children() {
+                if let Some(items) = category.get_default_structure() {
+                    item_types.extend(items.keys().cloned());
+                }
+            }
+            item_types
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn test_config() -> ProjectConfig {
+        serde_json::from_str(
+            r#"{
+                "project": {
+                    "name": "app",
+                    "version": "1.0",
+                    "structure": {
+                        "modules": {
+                            "allow_dynamic_children": true,
+                            "default_structure": {
+                                "components": { "template": "t.hbs", "file_extension": "tsx" },
+                                "hooks": { "template": "t.hbs", "file_extension": "ts" }
+                            }
+                        },
+                        "pages": {
+                            "children": {
+                                "screens": { "template": "t.hbs", "file_extension": "tsx" }
+                            }
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_complete_create_path() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("modules/cats")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("modules/users")).unwrap();
+        let config = test_config();
+        let complete = |current: &str| complete_create_path(&config, temp_dir.path(), current);
+
+        assert_eq!(complete(""), vec!["cats/", "users/", "modules/", "pages/"]);
+        assert_eq!(complete("c"), vec!["cats/"]);
+        assert_eq!(complete("cats/"), vec!["cats/components/", "cats/hooks/"]);
+        assert_eq!(complete("cats/h"), vec!["cats/hooks/"]);
+        assert_eq!(complete("dogs/s"), vec!["dogs/screens/"]);
+        assert!(complete("cats/components/").is_empty());
+
+        // Paths starting with a category walk the tree
+        assert_eq!(
+            complete("modules/"),
+            vec!["modules/cats/", "modules/users/"]
+        );
+        assert_eq!(
+            complete("modules/cats/"),
+            vec!["modules/cats/components/", "modules/cats/hooks/"]
+        );
+        assert_eq!(complete("pages/"), vec!["pages/screens/"]);
+
+        // An explicit category keeps its prefix
+        assert_eq!(complete("modules:u"), vec!["modules:users/"]);
+        assert_eq!(
+            complete("modules:cats/co"),
+            vec!["modules:cats/components/"]
+        );
+    }
+
+    #[test]
+    fn test_completion_scripts() {
+        for shell in [
+            CompletionShell::Bash,
+            CompletionShell::Zsh,
+            CompletionShell::Fish,
+        ] {
+            let script = completion_script(shell);
+            assert!(script.contains("creator"));
+            assert!(script.contains("__complete"), "{:?}", shell);
+            assert!(!script.contains("__complete -h"), "{:?}", shell);
+        }
+        assert!(completion_script(CompletionShell::Bash).contains("complete -F _creator_dynamic"));
+        assert!(
+            completion_script(CompletionShell::Zsh).contains("compdef _creator_dynamic creator")
+        );
+    }
+}
@@ -1,38 +1,523 @@
 use anyhow::{anyhow, Result};
+use clap::ValueEnum;
+use indexmap::IndexMap;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::diagnostics::{child_path, Diagnostics, ValidationErrors};
+use crate::extends::{load_layers, merge_layers};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectConfig {
+    /// Version of the config format, see `crate::migrate`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<u64>,
+    /// Config file or built-in preset this config extends, as declared by the loaded file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
     pub project: ProjectInfo,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<Preferences>,
+    /// Directory that relative template paths are resolved against instead of the
+    /// current directory. Set for workspace projects to the project root.
+    #[serde(skip)]
+    pub base_dir: Option<PathBuf>,
+    /// Directory of personal template overrides, checked before any other location
+    #[serde(skip)]
+    pub template_overrides: Option<PathBuf>,
+    /// Config file of the `extends` chain that sets `templates_dir`
+    #[serde(skip)]
+    pub templates_dir_source: Option<PathBuf>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectInfo {
     pub name: String,
     pub version: String,
-    pub structure: HashMap<String, Category>,
+    pub structure: IndexMap<String, Category>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name_case: Option<FileNameCase>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation: Option<ProjectValidation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates_dir: Option<String>,
+    /// Commands run around every create
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hooks: Option<Hooks>,
+    /// What create does with the git repository the project lives in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git: Option<GitOptions>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Category {
     pub description: Option<String>,
-    pub children: Option<HashMap<String, Item>>,
+    pub children: Option<IndexMap<String, Item>>,
     pub allow_dynamic_children: Option<bool>,
-    pub default_structure: Option<HashMap<String, Item>>,
+    pub default_structure: Option<IndexMap<String, Item>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name_case: Option<FileNameCase>,
+    /// Static nested categories, each a folder inside this category
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub categories: Option<IndexMap<String, Category>>,
+    /// Structure of each dynamic child when it needs nesting; replaces `default_structure`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module_structure: Option<Box<Category>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Item {
     pub template: String,
     pub file_extension: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub naming: Option<ItemNaming>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name_case: Option<FileNameCase>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation: Option<NameValidation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variables: Option<IndexMap<String, Variable>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<ItemFile>,
+    /// Commands run around creating this item, after the project's
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hooks: Option<Hooks>,
+}
+
+/// Additional output generated next to the item's main file, e.g. a test or styles file.
+/// The file name is the item's file name followed by `file_extension` (`cat-list.test.tsx`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ItemFile {
+    pub template: String,
+    pub file_extension: String,
+    /// Condition evaluated against the render context, e.g. `withStyles` or `!tests`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<String>,
+}
+
+/// Shell commands run before and after files are generated. Commands may use the
+/// placeholders in `crate::hooks::PLACEHOLDERS`, e.g. `prettier --write {files}`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Hooks {
+    /// Run before any file is written; a failing command cancels the create
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_generate: Vec<String>,
+    /// Run after the files are written, with `{files}` listing the files created
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_generate: Vec<String>,
+    /// Seconds each command, and the processes it starts, may run before they are
+    /// stopped and the command is counted as failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// What a failing post_generate command does
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_failure: Option<HookFailure>,
+}
+
+/// What to do when a post_generate hook fails
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HookFailure {
+    /// Print a warning and keep the generated files
+    #[default]
+    Warn,
+    /// Remove the generated files, restore overwritten ones and fail the create
+    Abort,
+}
+
+impl Hooks {
+    /// Validate the hook commands and their timeout
+    pub fn validate(&self, context: &str) -> Result<()> {
+        if self.timeout == Some(0) {
+            return Err(anyhow!(
+                "Hooks in {} have a timeout of 0 seconds. 💡 Use a positive number of seconds, or remove timeout for the default",
+                context
+            ));
+        }
+
+        for (stage, commands) in [
+            ("pre_generate", &self.pre_generate),
+            ("post_generate", &self.post_generate),
+        ] {
+            for command in commands {
+                if command.trim().is_empty() {
+                    return Err(anyhow!(
+                        "Hooks in {} have an empty {} command",
+                        context,
+                        stage
+                    ));
+                }
+                if let Some(unknown) = crate::hooks::unknown_placeholder(command) {
+                    return Err(anyhow!(
+                        "Hook '{}' in {} uses unknown placeholder '{{{}}}'. 💡 Available placeholders: {}",
+                        command,
+                        context,
+                        unknown,
+                        crate::hooks::PLACEHOLDERS
+                            .iter()
+                            .map(|name| format!("{{{}}}", name))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Git integration for `create`. Git runs in the project root, on the files the
+/// create writes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GitOptions {
+    /// Refuse to create when a file about to be written has uncommitted changes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_clean: Option<bool>,
+    /// `git add` the created files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<bool>,
+    /// Commit the created files, staging them first
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<bool>,
+    /// Message of that commit, using the hook placeholders. Defaults to
+    /// `crate::git::DEFAULT_COMMIT_MESSAGE`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_message: Option<String>,
+}
+
+impl GitOptions {
+    /// Validate the commit message template
+    pub fn validate(&self) -> Result<()> {
+        let Some(message) = &self.commit_message else {
+            return Ok(());
+        };
+
+        if message.trim().is_empty() {
+            return Err(anyhow!(
+                "Git commit_message cannot be empty. 💡 Remove it to use the default '{}'",
+                crate::git::DEFAULT_COMMIT_MESSAGE
+            ));
+        }
+        if let Some(unknown) = crate::hooks::unknown_placeholder(message) {
+            return Err(anyhow!(
+                "Git commit_message '{}' uses unknown placeholder '{{{}}}'. 💡 Available placeholders: {}",
+                message,
+                unknown,
+                crate::hooks::PLACEHOLDERS
+                    .iter()
+                    .map(|name| format!("{{{}}}", name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Extra template variable declared by an item, prompted for or passed with `--set key=value`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Variable {
+    #[serde(rename = "type")]
+    pub var_type: VariableType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariableType {
+    Bool,
+    String,
+    Enum,
+}
+
+impl Variable {
+    /// Validate the variable declaration (name, enum options, default type)
+    pub fn validate(&self, name: &str, context: &str) -> Result<()> {
+        if name == "templateName" || name == crate::variables::TESTS_VARIABLE {
+            return Err(anyhow!(
+                "Variable '{}' in {} is reserved by Creator",
+                name,
+                context
+            ));
+        }
+
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(anyhow!(
+                "Variable '{}' in {} must contain only letters, numbers and underscores",
+                name,
+                context
+            ));
+        }
+
+        if self.var_type == VariableType::Enum && self.options.is_empty() {
+            return Err(anyhow!(
+                "Enum variable '{}' in {} must declare options",
+                name,
+                context
+            ));
+        }
+
+        let Some(default) = &self.default else {
+            return Ok(());
+        };
+
+        let default_matches = match self.var_type {
+            VariableType::Bool => default.is_boolean(),
+            VariableType::String => default.is_string(),
+            VariableType::Enum => default
+                .as_str()
+                .is_some_and(|d| self.options.iter().any(|o| o == d)),
+        };
+
+        if !default_matches {
+            return Err(anyhow!(
+                "Variable '{}' in {} has a default value {} that does not match its type",
+                name,
+                context,
+                default
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Defaults for how creator behaves, set by the project config or the user config
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Preferences {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_conflict: Option<ConflictPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<OutputFormat>,
+    /// Command used to open generated files with `--open`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<String>,
+}
+
+impl Preferences {
+    /// Keep the values set here and fill the others from `base`
+    pub fn or(self, base: Preferences) -> Preferences {
+        Preferences {
+            on_conflict: self.on_conflict.or(base.on_conflict),
+            output: self.output.or(base.output),
+            editor: self.editor.or(base.editor),
+        }
+    }
+}
+
+/// What to do when a generated file already exists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictPolicy {
+    #[default]
+    Overwrite,
+    Skip,
+    Error,
+}
+
+/// How command results are printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Project-wide validation rules for module names and item names
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProjectValidation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module: Option<NameValidation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<NameValidation>,
+}
+
+/// Rules a user-provided name must satisfy, on top of the built-in character check
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NameValidation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reserved: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbid_existing_modules: Option<bool>,
+    /// `pattern` once compiled, so names checked on every keystroke reuse it
+    #[serde(skip)]
+    pub compiled_pattern: CompiledPattern,
+}
+
+/// A compiled validation pattern, filled on first use. Two patterns are equal when
+/// their sources are; one that isn't compiled yet only mirrors `pattern`, which is
+/// compared on its own.
+#[derive(Debug, Clone, Default)]
+pub struct CompiledPattern(OnceLock<Regex>);
+
+impl PartialEq for CompiledPattern {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.0.get(), other.0.get()) {
+            (Some(regex), Some(other)) => regex.as_str() == other.as_str(),
+            _ => true,
+        }
+    }
+}
+
+impl NameValidation {
+    /// The compiled `pattern`, compiled the first time it's needed and reused afterwards
+    pub fn pattern_regex(&self) -> std::result::Result<Option<&Regex>, regex::Error> {
+        let Some(pattern) = &self.pattern else {
+            return Ok(None);
+        };
+        if let Some(regex) = self.compiled_pattern.0.get() {
+            return Ok(Some(regex));
+        }
+
+        let regex = Regex::new(pattern)?;
+        Ok(Some(self.compiled_pattern.0.get_or_init(|| regex)))
+    }
+
+    /// Validate the rules themselves (regex syntax, length bounds)
+    pub fn validate(&self, context: &str) -> Result<()> {
+        if let Err(e) = self.pattern_regex() {
+            return Err(anyhow!(
+                "Invalid validation pattern '{}' in {}: {}",
+                self.pattern.as_deref().unwrap_or_default(),
+                context,
+                e
+            ));
+        }
+
+        if let (Some(min), Some(max)) = (self.min_length, self.max_length) {
+            if min > max {
+                return Err(anyhow!(
+                    "Validation in {} has min_length {} greater than max_length {}",
+                    context,
+                    min,
+                    max
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Naming rules for the identifier passed to the template and the output file name
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ItemNaming {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_name: Option<NameRule>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<NameRule>,
+}
+
+/// Transformation applied to a user-provided name: strip prefixes, change case, add affixes
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NameRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub case: Option<CaseStyle>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub strip_prefixes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CaseStyle {
+    Kebab,
+    Pascal,
+    Camel,
+    Snake,
+    Preserve,
+}
+
+/// Output file name policy: a case style (`"pascal"`) or a pattern (`{ "pattern": "{pascal}.screen" }`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FileNameCase {
+    Style(CaseStyle),
+    Pattern { pattern: String },
+}
+
+/// Placeholders accepted in file name patterns
+pub const FILE_NAME_PLACEHOLDERS: [&str; 5] = ["name", "kebab", "pascal", "camel", "snake"];
+
+impl FileNameCase {
+    /// Validate a file name pattern; case styles are always valid
+    pub fn validate(&self, context: &str) -> Result<()> {
+        let FileNameCase::Pattern { pattern } = self else {
+            return Ok(());
+        };
+
+        if pattern.contains('/') || pattern.contains('\\') {
+            return Err(anyhow!(
+                "File name pattern '{}' in {} cannot contain path separators",
+                pattern,
+                context
+            ));
+        }
+
+        let mut placeholders = 0;
+        let mut rest = pattern.as_str();
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..].find('}').ok_or_else(|| {
+                anyhow!(
+                    "File name pattern '{}' in {} has an unclosed placeholder",
+                    pattern,
+                    context
+                )
+            })?;
+            let placeholder = &rest[start + 1..start + end];
+            if !FILE_NAME_PLACEHOLDERS.contains(&placeholder) {
+                return Err(anyhow!(
+                    "File name pattern '{}' in {} uses unknown placeholder '{{{}}}'. Available: {}",
+                    pattern,
+                    context,
+                    placeholder,
+                    FILE_NAME_PLACEHOLDERS
+                        .iter()
+                        .map(|p| format!("{{{}}}", p))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+            placeholders += 1;
+            rest = &rest[start + end + 1..];
+        }
+
+        if placeholders == 0 {
+            return Err(anyhow!(
+                "File name pattern '{}' in {} must contain at least one placeholder",
+                pattern,
+                context
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 impl ProjectConfig {
     /// Load and validate project configuration from file
-    pub fn load_and_validate(config_path: &PathBuf) -> Result<Self> {
+    pub fn load_and_validate(config_path: &Path) -> Result<Self> {
         // Check if file exists
         if !config_path.exists() {
             return Err(anyhow!(
@@ -41,13 +526,40 @@ impl ProjectConfig {
             ));
         }
 
-        // Read file contents
-        let contents = fs::read_to_string(config_path)
-            .map_err(|e| anyhow!("Failed to read config file: {}", e))?;
+        // Read the file and every config it extends, base first
+        let layers = load_layers(config_path)?;
+        let merged = merge_layers(&layers, layers.len());
+
+        // Point errors at the file, line and column that declares the offending value
+        let mut config = Self::from_value(merged).map_err(|error| {
+            match error.downcast::<ValidationErrors>() {
+                Ok(mut errors) => {
+                    errors.locate(&layers);
+                    errors.into()
+                }
+                Err(error) => error,
+            }
+        })?;
+        config.extends = layers.last().and_then(|layer| layer.extends.clone());
+        config.templates_dir_source = layers
+            .iter()
+            .rev()
+            .find(|layer| layer.value["project"].get("templates_dir").is_some())
+            .filter(|layer| !layer.value["project"]["templates_dir"].is_null())
+            .map(|layer| PathBuf::from(&layer.source));
+
+        Ok(config)
+    }
 
-        // Parse JSON
-        let config: ProjectConfig = serde_json::from_str(&contents)
-            .map_err(|e| anyhow!("Failed to parse config JSON: {}", e))?;
+    /// Parse and validate a config from its JSON value
+    fn from_value(value: serde_json::Value) -> Result<Self> {
+        let config: ProjectConfig = serde_path_to_error::deserialize(value).map_err(|e| {
+            let path = e.path().to_string();
+            ValidationErrors::at(
+                if path == "." { "" } else { &path },
+                format!("Failed to parse config JSON: {}", e.inner()),
+            )
+        })?;
 
         // Validate configuration
         config.validate()?;
@@ -55,189 +567,1095 @@ impl ProjectConfig {
         Ok(config)
     }
 
-    /// Validate the entire configuration
+    /// Validate the entire configuration, reporting every problem at once
     pub fn validate(&self) -> Result<()> {
+        let mut diagnostics = Diagnostics::default();
+
         // Validate project info
         if self.project.name.is_empty() {
-            return Err(anyhow!("Project name cannot be empty"));
+            diagnostics.push("project.name", "Project name cannot be empty");
         }
 
         if self.project.version.is_empty() {
-            return Err(anyhow!("Project version cannot be empty"));
+            diagnostics.push("project.version", "Project version cannot be empty");
         }
 
         if self.project.structure.is_empty() {
-            return Err(anyhow!("Project structure cannot be empty"));
+            diagnostics.push("project.structure", "Project structure cannot be empty");
+        }
+
+        if let Some(file_name_case) = &self.project.file_name_case {
+            diagnostics.check("project.file_name_case", file_name_case.validate("project"));
+        }
+
+        if let Some(templates_dir) = &self.project.templates_dir {
+            if templates_dir.is_empty() {
+                diagnostics.push(
+                    "project.templates_dir",
+                    "Project templates_dir cannot be empty",
+                );
+            }
+        }
+
+        if let Some(hooks) = &self.project.hooks {
+            diagnostics.check("project.hooks", hooks.validate("project"));
+        }
+
+        if let Some(git) = &self.project.git {
+            diagnostics.check("project.git", git.validate());
+        }
+
+        if let Some(validation) = &self.project.validation {
+            if let Some(module_rules) = &validation.module {
+                diagnostics.check(
+                    "project.validation.module",
+                    module_rules.validate("project module validation"),
+                );
+            }
+            if let Some(item_rules) = &validation.item {
+                diagnostics.check(
+                    "project.validation.item",
+                    item_rules.validate("project item validation"),
+                );
+            }
         }
 
         // Validate each category
         for (category_name, category) in &self.project.structure {
-            category.validate(category_name)?;
+            category.collect_errors(
+                category_name,
+                &child_path("project.structure", category_name),
+                &mut diagnostics,
+            );
         }
 
-        Ok(())
+        diagnostics.into_result()
     }
 
-    /// Get available category names
+    /// Get available category names in declaration order
     pub fn get_categories(&self) -> Vec<String> {
         self.project.structure.keys().cloned().collect()
     }
 
-    /// Get category by name
-    pub fn get_category(&self, name: &str) -> Option<&Category> {
-        self.project.structure.get(name)
+    /// Get category by name
+    pub fn get_category(&self, name: &str) -> Option<&Category> {
+        self.project.structure.get(name)
+    }
+
+    /// Get the shared templates directory, if configured
+    pub fn templates_dir(&self) -> Option<PathBuf> {
+        let dir = PathBuf::from(self.project.templates_dir.as_ref()?);
+        Some(self.relative_to_base(dir))
+    }
+
+    /// A relative path resolved against the base directory when there is one, and
+    /// against the working directory otherwise
+    fn relative_to_base(&self, path: PathBuf) -> PathBuf {
+        match &self.base_dir {
+            Some(base_dir) if path.is_relative() => base_dir.join(path),
+            _ => path,
+        }
+    }
+
+    /// Resolve an item template path. Personal overrides win; relative paths are
+    /// resolved against the base directory (the working directory without one), then
+    /// looked up in the shared templates directory
+    pub fn resolve_template_path(&self, template: &str) -> PathBuf {
+        let path = self.resolve_project_template(template);
+        match self.template_overrides.as_ref() {
+            Some(dir) => self
+                .override_name(&path)
+                .map(|name| dir.join(name))
+                .filter(|path| path.exists())
+                .unwrap_or(path),
+            None => path,
+        }
+    }
+
+    fn resolve_project_template(&self, template: &str) -> PathBuf {
+        let path = self.relative_to_base(PathBuf::from(template));
+        if path.is_absolute() || path.exists() {
+            return path;
+        }
+
+        match self.templates_dir() {
+            Some(dir) if dir.join(template).exists() => dir.join(template),
+            _ => path,
+        }
+    }
+
+    /// Where an override of a project template lives inside the overrides directory:
+    /// its path within the shared templates directory, or else its file name
+    fn override_name<'a>(&self, template: &'a Path) -> Option<&'a Path> {
+        self.templates_dir()
+            .and_then(|dir| template.strip_prefix(dir).ok())
+            .or_else(|| template.file_name().map(Path::new))
+    }
+
+    /// Every template used by an item, main and extra files, resolved and without duplicates
+    pub fn declared_templates(&self) -> Vec<PathBuf> {
+        fn collect(category: &Category, templates: &mut Vec<String>) {
+            let items = category
+                .children
+                .iter()
+                .chain(category.default_structure.iter())
+                .flat_map(|items| items.values());
+            for item in items {
+                templates.push(item.template.clone());
+                templates.extend(item.files.iter().map(|file| file.template.clone()));
+            }
+
+            for subcategory in category.categories.iter().flat_map(|c| c.values()) {
+                collect(subcategory, templates);
+            }
+            if let Some(module_structure) = category.get_module_structure() {
+                collect(module_structure, templates);
+            }
+        }
+
+        let mut declared = Vec::new();
+        for category in self.project.structure.values() {
+            collect(category, &mut declared);
+        }
+
+        let mut templates: Vec<PathBuf> = Vec::new();
+        for template in declared {
+            let path = self.resolve_template_path(&template);
+            if !templates.contains(&path) {
+                templates.push(path);
+            }
+        }
+        templates
+    }
+
+    /// Get the effective preferences, unset values left to the built-in defaults
+    pub fn preferences(&self) -> Preferences {
+        self.preferences.clone().unwrap_or_default()
+    }
+
+    /// Get the validation rules for module names
+    pub fn module_validation(&self) -> Vec<&NameValidation> {
+        self.project
+            .validation
+            .as_ref()
+            .and_then(|v| v.module.as_ref())
+            .into_iter()
+            .collect()
+    }
+
+    /// Get the validation rules for item names: project-wide rules, then the item's own
+    pub fn item_validation<'a>(&'a self, item: &'a Item) -> Vec<&'a NameValidation> {
+        self.project
+            .validation
+            .as_ref()
+            .and_then(|v| v.item.as_ref())
+            .into_iter()
+            .chain(item.validation.as_ref())
+            .collect()
+    }
+
+    /// Resolve the file name case policy for an item: item, then category, then project
+    pub fn resolve_file_name_case<'a>(
+        &'a self,
+        category: Option<&'a Category>,
+        item: &'a Item,
+    ) -> Option<&'a FileNameCase> {
+        item.file_name_case
+            .as_ref()
+            .or_else(|| category?.file_name_case.as_ref())
+            .or(self.project.file_name_case.as_ref())
+    }
+}
+
+impl Category {
+    /// Validate category configuration
+    pub fn validate(&self, category_name: &str) -> Result<()> {
+        let mut diagnostics = Diagnostics::default();
+        self.collect_errors(category_name, "", &mut diagnostics);
+        diagnostics.into_result()
+    }
+
+    /// Record every problem of the category declared at JSON `path`
+    fn collect_errors(&self, category_name: &str, path: &str, diagnostics: &mut Diagnostics) {
+        if let Some(file_name_case) = &self.file_name_case {
+            diagnostics.check(
+                &child_path(path, "file_name_case"),
+                file_name_case.validate(&format!("category '{}'", category_name)),
+            );
+        }
+
+        if self.categories.is_some() || self.module_structure.is_some() {
+            self.collect_nested_errors(category_name, path, diagnostics);
+        } else if let Some((key, problem)) = self.layout_problem() {
+            diagnostics.push(
+                &child_path(path, key),
+                format!("Category '{}' {}", category_name, problem),
+            );
+        }
+
+        self.collect_item_errors(category_name, path, diagnostics);
+    }
+
+    /// Explain what's wrong with the combination of `children`, `allow_dynamic_children`
+    /// and `default_structure`, naming the key to point at (empty for the category itself)
+    fn layout_problem(&self) -> Option<(&'static str, &'static str)> {
+        let children = self.children.as_ref().map(|children| children.is_empty());
+        let default_structure = self
+            .default_structure
+            .as_ref()
+            .map(|default_structure| default_structure.is_empty());
+
+        match (children, self.allow_dynamic_children, default_structure) {
+            // Static children only
+            (Some(false), None, None) => None,
+            (Some(true), None, None) => Some((
+                "children",
+                "has empty children but no dynamic support. 💡 Add items to children, or set allow_dynamic_children: true with a default_structure",
+            )),
+            // Dynamic children, with or without static children
+            (None, Some(true), Some(false)) | (Some(_), Some(true), Some(_)) => None,
+            (None, Some(true), Some(true)) => Some((
+                "default_structure",
+                "allows dynamic children but has empty default structure. 💡 Add at least one item type to default_structure",
+            )),
+            (_, Some(true), None) => Some((
+                "allow_dynamic_children",
+                "allows dynamic children but has no default structure. 💡 Add a default_structure listing the item types every module gets",
+            )),
+            (None, None, None) => Some((
+                "",
+                "must have either children or dynamic support. 💡 Add children for fixed items, or allow_dynamic_children: true with a default_structure for modules",
+            )),
+            (None, Some(false), None) => Some((
+                "allow_dynamic_children",
+                "has dynamic children disabled but no static children. 💡 Add children, or set allow_dynamic_children to true with a default_structure",
+            )),
+            (None, Some(false), Some(_)) => Some((
+                "allow_dynamic_children",
+                "has dynamic children disabled but no static children, so its default_structure is never used. 💡 Set allow_dynamic_children to true",
+            )),
+            (Some(_), Some(false), Some(_)) => Some((
+                "default_structure",
+                "has a default_structure but dynamic children are disabled. 💡 Set allow_dynamic_children to true, or remove default_structure",
+            )),
+            (_, None, Some(_)) => Some((
+                "default_structure",
+                "has a default_structure but allow_dynamic_children is not set. 💡 Set allow_dynamic_children: true, or remove default_structure",
+            )),
+            (Some(_), Some(false), None) => Some((
+                "allow_dynamic_children",
+                "sets allow_dynamic_children to false next to its children, which is already the default. 💡 Remove allow_dynamic_children",
+            )),
+        }
+    }
+
+    /// Record problems of a category that uses nested categories or a module structure
+    fn collect_nested_errors(
+        &self,
+        category_name: &str,
+        path: &str,
+        diagnostics: &mut Diagnostics,
+    ) {
+        if self
+            .children
+            .as_ref()
+            .is_some_and(|children| children.is_empty())
+        {
+            diagnostics.push(
+                &child_path(path, "children"),
+                format!("Category '{}' has empty children", category_name),
+            );
+        }
+
+        if let Some(categories) = &self.categories {
+            let categories_path = child_path(path, "categories");
+            if categories.is_empty() {
+                diagnostics.push(
+                    &categories_path,
+                    format!("Category '{}' has empty nested categories", category_name),
+                );
+            }
+            for (name, category) in categories {
+                category.collect_errors(
+                    &format!("{}/{}", category_name, name),
+                    &child_path(&categories_path, name),
+                    diagnostics,
+                );
+            }
+        }
+
+        let problem = match (
+            self.supports_dynamic_children(),
+            &self.default_structure,
+            &self.module_structure,
+        ) {
+            (true, Some(_), Some(_)) => Some((
+                "module_structure",
+                "cannot have both default_structure and module_structure".to_string(),
+            )),
+            (true, Some(default_structure), None) if default_structure.is_empty() => Some((
+                "default_structure",
+                "allows dynamic children but has empty default structure".to_string(),
+            )),
+            (true, Some(_), None) => None,
+            (true, None, Some(module_structure)) => {
+                module_structure.collect_errors(
+                    &format!("{}/<module>", category_name),
+                    &child_path(path, "module_structure"),
+                    diagnostics,
+                );
+                None
+            }
+            (true, None, None) => Some((
+                "allow_dynamic_children",
+                "allows dynamic children but has no default structure".to_string(),
+            )),
+            (false, _, Some(_)) => Some((
+                "module_structure",
+                "has a module_structure but dynamic children are not enabled".to_string(),
+            )),
+            (false, Some(_), None) => Some((
+                "default_structure",
+                "has a default_structure but dynamic children are not enabled".to_string(),
+            )),
+            (false, None, None) => None,
+        };
+
+        if let Some((key, problem)) = problem {
+            diagnostics.push(
+                &child_path(path, key),
+                format!("Category '{}' {}", category_name, problem),
+            );
+        }
+    }
+
+    /// Record problems of the items in `children` and `default_structure`
+    fn collect_item_errors(&self, category_name: &str, path: &str, diagnostics: &mut Diagnostics) {
+        for (key, items) in [
+            ("children", &self.children),
+            ("default_structure", &self.default_structure),
+        ] {
+            let items_path = child_path(path, key);
+            for (item_name, item) in items.iter().flatten() {
+                item.collect_errors(
+                    category_name,
+                    item_name,
+                    &child_path(&items_path, item_name),
+                    diagnostics,
+                );
+            }
+        }
+    }
+
+    /// Get available item names (static children only)
+    pub fn get_item_names(&self) -> Vec<String> {
+        self.children
+            .as_ref()
+            .map(|children| children.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Get item by name
+    pub fn get_item(&self, name: &str) -> Option<&Item> {
+        self.children.as_ref()?.get(name)
+    }
+
+    /// Get item by name from static children, then from the default structure
+    pub fn find_item(&self, name: &str) -> Option<&Item> {
+        self.get_item(name)
+            .or_else(|| self.default_structure.as_ref()?.get(name))
+    }
+
+    /// Check if category supports dynamic children
+    pub fn supports_dynamic_children(&self) -> bool {
+        self.allow_dynamic_children.unwrap_or(false)
+    }
+
+    /// Get default structure for dynamic children
+    pub fn get_default_structure(&self) -> Option<&IndexMap<String, Item>> {
+        self.default_structure.as_ref()
+    }
+
+    /// Get nested category by name
+    pub fn get_subcategory(&self, name: &str) -> Option<&Category> {
+        self.categories.as_ref()?.get(name)
+    }
+
+    /// Get nested category names
+    pub fn get_subcategory_names(&self) -> Vec<String> {
+        self.categories
+            .as_ref()
+            .map(|categories| categories.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Get the nested structure of each dynamic child, if configured
+    pub fn get_module_structure(&self) -> Option<&Category> {
+        self.module_structure.as_deref()
+    }
+}
+
+impl Item {
+    /// Get the naming rule for the template identifier, if configured
+    pub fn template_name_rule(&self) -> Option<&NameRule> {
+        self.naming.as_ref()?.template_name.as_ref()
+    }
+
+    /// Get the naming rule for the output file name, if configured
+    pub fn file_name_rule(&self) -> Option<&NameRule> {
+        self.naming.as_ref()?.file_name.as_ref()
+    }
+
+    /// Validate item configuration
+    pub fn validate(&self, category_name: &str, item_name: &str) -> Result<()> {
+        let mut diagnostics = Diagnostics::default();
+        self.collect_errors(category_name, item_name, "", &mut diagnostics);
+        diagnostics.into_result()
+    }
+
+    /// Record every problem of the item declared at JSON `path`
+    fn collect_errors(
+        &self,
+        category_name: &str,
+        item_name: &str,
+        path: &str,
+        diagnostics: &mut Diagnostics,
+    ) {
+        let context = format!("item '{}' in category '{}'", item_name, category_name);
+
+        if self.template.is_empty() {
+            diagnostics.push(
+                &child_path(path, "template"),
+                format!(
+                    "Item '{}' in category '{}' has empty template path",
+                    item_name, category_name
+                ),
+            );
+        }
+
+        if self.file_extension.is_empty() {
+            diagnostics.push(
+                &child_path(path, "file_extension"),
+                format!(
+                    "Item '{}' in category '{}' has empty file extension",
+                    item_name, category_name
+                ),
+            );
+        }
+
+        let file_extensions =
+            std::iter::once((child_path(path, "file_extension"), &self.file_extension)).chain(
+                self.files.iter().enumerate().map(|(index, file)| {
+                    (
+                        format!("{}[{}].file_extension", child_path(path, "files"), index),
+                        &file.file_extension,
+                    )
+                }),
+            );
+        for (extension_path, file_extension) in file_extensions {
+            if file_extension.starts_with('.') {
+                diagnostics.push(
+                    &extension_path,
+                    format!(
+                        "Item '{}' in category '{}' has file extension '{}' starting with a dot. 💡 Write it without the dot, or run `creator config migrate`",
+                        item_name, category_name, file_extension
+                    ),
+                );
+            }
+        }
+
+        if let Some(file_name_case) = &self.file_name_case {
+            diagnostics.check(
+                &child_path(path, "file_name_case"),
+                file_name_case.validate(&context),
+            );
+        }
+
+        if let Some(validation) = &self.validation {
+            diagnostics.check(
+                &child_path(path, "validation"),
+                validation.validate(&context),
+            );
+        }
+
+        if let Some(hooks) = &self.hooks {
+            diagnostics.check(&child_path(path, "hooks"), hooks.validate(&context));
+        }
+
+        if let Some(variables) = &self.variables {
+            for (variable_name, variable) in variables {
+                diagnostics.check(
+                    &child_path(&child_path(path, "variables"), variable_name),
+                    variable.validate(variable_name, &context),
+                );
+            }
+        }
+
+        for (index, file) in self.files.iter().enumerate() {
+            let file_path = format!("{}[{}]", child_path(path, "files"), index);
+            if file.template.is_empty() || file.file_extension.is_empty() {
+                diagnostics.push(
+                    &file_path,
+                    format!(
+                        "Item '{}' in category '{}' has an extra file with empty template or file extension",
+                        item_name, category_name
+                    ),
+                );
+            }
+
+            if let Some(condition) = &file.when {
+                let when_path = child_path(&file_path, "when");
+                let referenced = match crate::variables::condition_variables(condition) {
+                    Ok(referenced) => referenced,
+                    Err(e) => {
+                        diagnostics.push(
+                            &when_path,
+                            format!(
+                                "Item '{}' in category '{}': {}",
+                                item_name, category_name, e
+                            ),
+                        );
+                        continue;
+                    }
+                };
+
+                for variable in referenced {
+                    let declared = self
+                        .variables
+                        .as_ref()
+                        .is_some_and(|variables| variables.contains_key(&variable));
+                    if !declared && variable != crate::variables::TESTS_VARIABLE {
+                        diagnostics.push(
+                            &when_path,
+                            format!(
+                                "Item '{}' in category '{}' has condition '{}' referencing undeclared variable '{}'",
+                                item_name, category_name, condition, variable
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        if let Some(file_rule) = self.naming.as_ref().and_then(|n| n.file_name.as_ref()) {
+            let affixes = [file_rule.prefix.as_deref(), file_rule.suffix.as_deref()];
+            if affixes
+                .iter()
+                .flatten()
+                .any(|affix| affix.contains('/') || affix.contains('\\'))
+            {
+                diagnostics.push(
+                    &child_path(&child_path(path, "naming"), "file_name"),
+                    format!(
+                        "Item '{}' in category '{}' has a file name prefix or suffix containing a path separator",
+                        item_name, category_name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migrate::CURRENT_SCHEMA_VERSION;
+    use std::fs;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_valid_config_parsing() {
+        let config_json = r#"
+        {
+            "project": {
+                "name": "test-project",
+                "version": "1.0",
+                "structure": {
+                    "features": {
+                        "description": "Business features",
+                        "children": {
+                            "modules": {
+                                "template": "templates/module.hbs",
+                                "file_extension": "tsx"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        "#;
+
+        let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
+        assert_eq!(config.project.name, "test-project");
+        assert_eq!(config.project.version, "1.0");
+        assert!(config.project.structure.contains_key("features"));
+
+        // Test validation
+        config.validate().unwrap();
+    }
+
+    #[test]
+    fn test_invalid_config_empty_name() {
+        let config_json = r#"
+        {
+            "project": {
+                "name": "",
+                "version": "1.0",
+                "structure": {
+                    "features": {
+                        "children": {
+                            "modules": {
+                                "template": "templates/module.hbs",
+                                "file_extension": "tsx"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        "#;
+
+        let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_dynamic_category_validation() {
+        let config_json = r#"
+        {
+            "project": {
+                "name": "test-project",
+                "version": "1.0",
+                "structure": {
+                    "features": {
+                        "description": "Dynamic features",
+                        "allow_dynamic_children": true,
+                        "default_structure": {
+                            "modules": {
+                                "template": "templates/module.hbs",
+                                "file_extension": "tsx"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        "#;
+
+        let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
+        config.validate().unwrap();
+
+        let features = config.get_category("features").unwrap();
+        assert!(features.supports_dynamic_children());
+        assert!(features.get_default_structure().is_some());
+    }
+
+    #[test]
+    fn test_mixed_category_validation() {
+        let config_json = r#"
+        {
+            "project": {
+                "name": "test-project",
+                "version": "1.0",
+                "structure": {
+                    "features": {
+                        "description": "Mixed features",
+                        "children": {
+                            "existing": {
+                                "template": "templates/existing.hbs",
+                                "file_extension": "tsx"
+                            }
+                        },
+                        "allow_dynamic_children": true,
+                        "default_structure": {
+                            "modules": {
+                                "template": "templates/module.hbs",
+                                "file_extension": "tsx"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        "#;
+
+        let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
+        config.validate().unwrap();
+    }
+
+    #[test]
+    fn test_item_naming_rules_parsing() {
+        let config_json = r#"
+        {
+            "project": {
+                "name": "test-project",
+                "version": "1.0",
+                "structure": {
+                    "data": {
+                        "allow_dynamic_children": true,
+                        "default_structure": {
+                            "repositories": {
+                                "template": "templates/default.hbs",
+                                "file_extension": "ts",
+                                "naming": {
+                                    "template_name": { "case": "pascal", "suffix": "Repository" },
+                                    "file_name": { "case": "snake", "strip_prefixes": ["repo-"] }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        "#;
+
+        let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
+        config.validate().unwrap();
+
+        let data = config.get_category("data").unwrap();
+        let item = data
+            .get_default_structure()
+            .unwrap()
+            .get("repositories")
+            .unwrap();
+        let template_rule = item.template_name_rule().unwrap();
+        assert_eq!(template_rule.case, Some(CaseStyle::Pascal));
+        assert_eq!(template_rule.suffix.as_deref(), Some("Repository"));
+        assert_eq!(item.file_name_rule().unwrap().strip_prefixes, vec!["repo-"]);
+    }
+
+    #[test]
+    fn test_item_naming_rejects_path_separators() {
+        let item = Item {
+            template: "templates/default.hbs".to_string(),
+            file_extension: "ts".to_string(),
+            naming: Some(ItemNaming {
+                template_name: None,
+                file_name: Some(NameRule {
+                    prefix: Some("../".to_string()),
+                    ..Default::default()
+                }),
+            }),
+            ..Default::default()
+        };
+
+        let result = item.validate("data", "repositories");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("path separator"));
+    }
+
+    #[test]
+    fn test_file_name_case_cascade() {
+        let config_json = r#"
+        {
+            "project": {
+                "name": "test-project",
+                "version": "1.0",
+                "file_name_case": "kebab",
+                "structure": {
+                    "modules": {
+                        "allow_dynamic_children": true,
+                        "file_name_case": "pascal",
+                        "default_structure": {
+                            "components": {
+                                "template": "templates/components.hbs",
+                                "file_extension": "tsx"
+                            },
+                            "screens": {
+                                "template": "templates/components.hbs",
+                                "file_extension": "tsx",
+                                "file_name_case": { "pattern": "{pascal}.screen" }
+                            }
+                        }
+                    },
+                    "shared": {
+                        "children": {
+                            "utils": {
+                                "template": "templates/default.hbs",
+                                "file_extension": "ts"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        "#;
+
+        let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
+        config.validate().unwrap();
+
+        let modules = config.get_category("modules").unwrap();
+        let default_structure = modules.get_default_structure().unwrap();
+        assert_eq!(
+            config.resolve_file_name_case(Some(modules), &default_structure["components"]),
+            Some(&FileNameCase::Style(CaseStyle::Pascal))
+        );
+        assert_eq!(
+            config.resolve_file_name_case(Some(modules), &default_structure["screens"]),
+            Some(&FileNameCase::Pattern {
+                pattern: "{pascal}.screen".to_string()
+            })
+        );
+
+        let shared = config.get_category("shared").unwrap();
+        assert_eq!(
+            config.resolve_file_name_case(Some(shared), shared.get_item("utils").unwrap()),
+            Some(&FileNameCase::Style(CaseStyle::Kebab))
+        );
+    }
+
+    #[test]
+    fn test_file_name_pattern_validation() {
+        let valid = FileNameCase::Pattern {
+            pattern: "use{pascal}".to_string(),
+        };
+        assert!(valid.validate("project").is_ok());
+
+        let invalid_patterns = vec![
+            ("{upper}", "unknown placeholder"),
+            ("static-name", "at least one placeholder"),
+            ("{kebab", "unclosed placeholder"),
+            ("../{kebab}", "path separators"),
+        ];
+
+        for (pattern, expected_error) in invalid_patterns {
+            let case = FileNameCase::Pattern {
+                pattern: pattern.to_string(),
+            };
+            let error_msg = case.validate("project").unwrap_err().to_string();
+            assert!(
+                error_msg.contains(expected_error),
+                "Unexpected error for '{}': {}",
+                pattern,
+                error_msg
+            );
+        }
     }
-}
 
-impl Category {
-    /// Validate category configuration
-    pub fn validate(&self, category_name: &str) -> Result<()> {
-        match (
-            &self.children,
-            &self.allow_dynamic_children,
-            &self.default_structure,
-        ) {
-            // Static children only
-            (Some(children), None, None) => {
-                if children.is_empty() {
-                    return Err(anyhow!(
-                        "Category '{}' has empty children but no dynamic support",
-                        category_name
-                    ));
-                }
-                self.validate_items(children, category_name)?;
-            }
-            // Dynamic children with default structure
-            (None, Some(true), Some(default_structure)) => {
-                if default_structure.is_empty() {
-                    return Err(anyhow!(
-                        "Category '{}' allows dynamic children but has empty default structure",
-                        category_name
-                    ));
+    #[test]
+    fn test_name_validation_parsing() {
+        let config_json = r#"
+        {
+            "project": {
+                "name": "test-project",
+                "version": "1.0",
+                "validation": {
+                    "module": { "pattern": "^[a-z][a-z0-9-]*$" },
+                    "item": { "reserved": ["index", "default"], "max_length": 40 }
+                },
+                "structure": {
+                    "modules": {
+                        "allow_dynamic_children": true,
+                        "default_structure": {
+                            "hooks": {
+                                "template": "templates/hooks.hbs",
+                                "file_extension": "ts",
+                                "validation": { "required_prefix": "use" }
+                            }
+                        }
+                    }
                 }
-                self.validate_items(default_structure, category_name)?;
-            }
-            // Static children + dynamic support
-            (Some(children), Some(true), Some(default_structure)) => {
-                self.validate_items(children, category_name)?;
-                self.validate_items(default_structure, category_name)?;
-            }
-            // Invalid configurations
-            (None, None, None) => {
-                return Err(anyhow!(
-                    "Category '{}' must have either children or dynamic support",
-                    category_name
-                ));
-            }
-            (None, Some(false), _) => {
-                return Err(anyhow!(
-                    "Category '{}' has dynamic children disabled but no static children",
-                    category_name
-                ));
-            }
-            (None, Some(true), None) => {
-                return Err(anyhow!(
-                    "Category '{}' allows dynamic children but has no default structure",
-                    category_name
-                ));
-            }
-            _ => {
-                return Err(anyhow!(
-                    "Category '{}' has invalid configuration",
-                    category_name
-                ));
             }
         }
+        "#;
 
-        Ok(())
-    }
+        let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
+        config.validate().unwrap();
 
-    /// Validate items within a category
-    fn validate_items(&self, items: &HashMap<String, Item>, category_name: &str) -> Result<()> {
-        for (item_name, item) in items {
-            item.validate(category_name, item_name)?;
-        }
-        Ok(())
+        assert_eq!(config.module_validation().len(), 1);
+        let modules = config.get_category("modules").unwrap();
+        let hooks = &modules.get_default_structure().unwrap()["hooks"];
+        let item_rules = config.item_validation(hooks);
+        assert_eq!(item_rules.len(), 2);
+        assert_eq!(item_rules[0].reserved, vec!["index", "default"]);
+        assert_eq!(item_rules[1].required_prefix.as_deref(), Some("use"));
     }
 
-    /// Get available item names (static children only)
-    pub fn get_item_names(&self) -> Vec<String> {
-        self.children
-            .as_ref()
-            .map(|children| children.keys().cloned().collect())
-            .unwrap_or_default()
-    }
+    #[test]
+    fn test_invalid_name_validation_rules() {
+        let invalid_regex = NameValidation {
+            pattern: Some("[a-z".to_string()),
+            ..Default::default()
+        };
+        let error_msg = invalid_regex.validate("project").unwrap_err().to_string();
+        assert!(error_msg.contains("Invalid validation pattern"));
 
-    /// Get item by name
-    pub fn get_item(&self, name: &str) -> Option<&Item> {
-        self.children.as_ref()?.get(name)
+        let invalid_bounds = NameValidation {
+            min_length: Some(10),
+            max_length: Some(2),
+            ..Default::default()
+        };
+        let error_msg = invalid_bounds.validate("project").unwrap_err().to_string();
+        assert!(error_msg.contains("greater than max_length"));
     }
 
-    /// Check if category supports dynamic children
-    pub fn supports_dynamic_children(&self) -> bool {
-        self.allow_dynamic_children.unwrap_or(false)
+    #[test]
+    fn test_compiled_pattern_equality() {
+        let rules = |pattern: &str| NameValidation {
+            pattern: Some(pattern.to_string()),
+            ..Default::default()
+        };
+        let (lower, upper) = (rules("^[a-z]+$"), rules("^[A-Z]+$"));
+        lower.pattern_regex().unwrap();
+        upper.pattern_regex().unwrap();
+        assert_ne!(lower.compiled_pattern, upper.compiled_pattern);
+        assert_ne!(lower, upper);
+
+        // Whether a pattern was compiled yet doesn't matter
+        assert_eq!(lower, rules("^[a-z]+$"));
+        assert_eq!(lower.compiled_pattern, lower.clone().compiled_pattern);
     }
 
-    /// Get default structure for dynamic children
-    pub fn get_default_structure(&self) -> Option<&HashMap<String, Item>> {
-        self.default_structure.as_ref()
+    #[test]
+    fn test_resolve_template_path_with_templates_dir() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let templates_dir = temp_dir.path().join("shared");
+        fs::create_dir_all(&templates_dir).unwrap();
+        fs::write(templates_dir.join("component.hbs"), "").unwrap();
+
+        let config = ProjectConfig {
+            project: ProjectInfo {
+                name: "test-project".to_string(),
+                version: "1.0".to_string(),
+                templates_dir: Some(templates_dir.to_string_lossy().to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.resolve_template_path("component.hbs"),
+            templates_dir.join("component.hbs")
+        );
+        // Unknown templates are returned unchanged
+        assert_eq!(
+            config.resolve_template_path("missing.hbs"),
+            PathBuf::from("missing.hbs")
+        );
     }
-}
 
-impl Item {
-    /// Validate item configuration
-    pub fn validate(&self, category_name: &str, item_name: &str) -> Result<()> {
-        if self.template.is_empty() {
-            return Err(anyhow!(
-                "Item '{}' in category '{}' has empty template path",
-                item_name,
-                category_name
-            ));
-        }
+    #[test]
+    fn test_resolve_paths_prefer_base_dir() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let base_dir = temp_dir.path().join("apps/web");
+        fs::create_dir_all(base_dir.join("templates")).unwrap();
+        fs::write(base_dir.join("templates/components.hbs"), "").unwrap();
 
-        if self.file_extension.is_empty() {
-            return Err(anyhow!(
-                "Item '{}' in category '{}' has empty file extension",
-                item_name,
-                category_name
-            ));
+        // `templates/components.hbs` also exists in the working directory
+        let config = ProjectConfig {
+            project: ProjectInfo {
+                templates_dir: Some("templates".to_string()),
+                ..Default::default()
+            },
+            base_dir: Some(base_dir.clone()),
+            ..Default::default()
+        };
+
+        assert_eq!(config.templates_dir(), Some(base_dir.join("templates")));
+        assert_eq!(
+            config.resolve_template_path("templates/components.hbs"),
+            base_dir.join("templates/components.hbs")
+        );
+        // Missing templates point into the base directory
+        assert_eq!(
+            config.resolve_template_path("templates/missing.hbs"),
+            base_dir.join("templates/missing.hbs")
+        );
+    }
+
+    #[test]
+    fn test_item_variables_parsing() {
+        let config_json = r#"
+        {
+            "project": {
+                "name": "test-project",
+                "version": "1.0",
+                "structure": {
+                    "modules": {
+                        "allow_dynamic_children": true,
+                        "default_structure": {
+                            "components": {
+                                "template": "templates/components.hbs",
+                                "file_extension": "tsx",
+                                "variables": {
+                                    "withProps": { "type": "bool", "default": true, "description": "Generate props" },
+                                    "routeName": { "type": "string" },
+                                    "stateLib": { "type": "enum", "options": ["redux", "zustand"], "default": "zustand" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
         }
+        "#;
 
-        // Validate template file exists (optional - can be skipped for performance)
-        // let template_path = PathBuf::from(&self.template);
-        // if !template_path.exists() {
-        //     return Err(anyhow!(
-        //         "Template file '{}' for item '{}' in category '{}' does not exist",
-        //         self.template,
-        //         item_name,
-        //         category_name
-        //     ));
-        // }
+        let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
+        config.validate().unwrap();
 
-        Ok(())
+        let modules = config.get_category("modules").unwrap();
+        let components = &modules.get_default_structure().unwrap()["components"];
+        let variables = components.variables.as_ref().unwrap();
+        assert_eq!(variables["withProps"].var_type, VariableType::Bool);
+        assert_eq!(variables["stateLib"].options, vec!["redux", "zustand"]);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use tempfile::NamedTempFile;
+    #[test]
+    fn test_invalid_variable_declarations() {
+        let enum_without_options = Variable {
+            var_type: VariableType::Enum,
+            default: None,
+            description: None,
+            options: Vec::new(),
+        };
+        assert!(enum_without_options
+            .validate("stateLib", "item")
+            .unwrap_err()
+            .to_string()
+            .contains("must declare options"));
+
+        let mismatched_default = Variable {
+            var_type: VariableType::Bool,
+            default: Some(serde_json::json!("yes")),
+            description: None,
+            options: Vec::new(),
+        };
+        assert!(mismatched_default
+            .validate("withProps", "item")
+            .unwrap_err()
+            .to_string()
+            .contains("does not match its type"));
+
+        let string_variable = Variable {
+            var_type: VariableType::String,
+            default: None,
+            description: None,
+            options: Vec::new(),
+        };
+        assert!(string_variable.validate("templateName", "item").is_err());
+        assert!(string_variable.validate("route-name", "item").is_err());
+        assert!(string_variable.validate("routeName", "item").is_ok());
+    }
 
     #[test]
-    fn test_valid_config_parsing() {
+    fn test_hooks_parsing_and_validation() {
         let config_json = r#"
         {
             "project": {
                 "name": "test-project",
                 "version": "1.0",
+                "hooks": {
+                    "post_generate": ["prettier --write {files}"],
+                    "timeout": 30,
+                    "on_failure": "abort"
+                },
                 "structure": {
-                    "features": {
-                        "description": "Business features",
+                    "pages": {
                         "children": {
-                            "modules": {
-                                "template": "templates/module.hbs",
-                                "file_extension": "tsx"
+                            "screens": {
+                                "template": "templates/screens.hbs",
+                                "file_extension": "tsx",
+                                "hooks": { "pre_generate": ["test ! -e {files}"] }
                             }
                         }
                     }
@@ -246,28 +1664,71 @@ mod tests {
         }
         "#;
 
-        let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
-        assert_eq!(config.project.name, "test-project");
-        assert_eq!(config.project.version, "1.0");
-        assert!(config.project.structure.contains_key("features"));
-
-        // Test validation
+        let mut config: ProjectConfig = serde_json::from_str(config_json).unwrap();
         config.validate().unwrap();
+        let hooks = config.project.hooks.as_ref().unwrap();
+        assert_eq!(hooks.timeout, Some(30));
+        assert_eq!(hooks.on_failure, Some(HookFailure::Abort));
+
+        let hooks = config.project.hooks.as_mut().unwrap();
+        hooks.post_generate = vec!["eslint --fix {file}".to_string()];
+        hooks.timeout = Some(0);
+        let error_msg = config.validate().unwrap_err().to_string();
+        assert!(error_msg.contains("project.hooks"));
+        assert!(error_msg.contains("timeout of 0 seconds"));
+
+        let hooks = config.project.hooks.as_mut().unwrap();
+        hooks.timeout = None;
+        let error_msg = config.validate().unwrap_err().to_string();
+        assert!(error_msg.contains("unknown placeholder '{file}'"));
+        assert!(error_msg.contains("{files}, {name}, {item_type}, {module}"));
     }
 
     #[test]
-    fn test_invalid_config_empty_name() {
+    fn test_git_options_validation() {
+        let mut options: GitOptions = serde_json::from_str(
+            r#"{ "require_clean": true, "commit": true, "commit_message": "feat({module}): add {name}" }"#,
+        )
+        .unwrap();
+        assert_eq!(options.require_clean, Some(true));
+        assert!(options.validate().is_ok());
+
+        options.commit_message = Some("add {component}".to_string());
+        assert!(options
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("unknown placeholder '{component}'"));
+
+        options.commit_message = Some(" ".to_string());
+        assert!(options
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("commit_message cannot be empty"));
+    }
+
+    #[test]
+    fn test_item_files_with_conditions() {
         let config_json = r#"
         {
             "project": {
-                "name": "",
+                "name": "test-project",
                 "version": "1.0",
                 "structure": {
-                    "features": {
-                        "children": {
-                            "modules": {
-                                "template": "templates/module.hbs",
-                                "file_extension": "tsx"
+                    "modules": {
+                        "allow_dynamic_children": true,
+                        "default_structure": {
+                            "components": {
+                                "template": "templates/components.hbs",
+                                "file_extension": "tsx",
+                                "variables": {
+                                    "withStyles": { "type": "bool", "default": false }
+                                },
+                                "files": [
+                                    { "template": "templates/test.hbs", "file_extension": "test.tsx", "when": "tests" },
+                                    { "template": "templates/styles.hbs", "file_extension": "styles.ts", "when": "withStyles" }
+                                ]
                             }
                         }
                     }
@@ -277,11 +1738,36 @@ mod tests {
         "#;
 
         let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
-        assert!(config.validate().is_err());
+        config.validate().unwrap();
+
+        let modules = config.get_category("modules").unwrap();
+        let components = &modules.get_default_structure().unwrap()["components"];
+        assert_eq!(components.files.len(), 2);
+        assert_eq!(components.files[1].when.as_deref(), Some("withStyles"));
     }
 
     #[test]
-    fn test_dynamic_category_validation() {
+    fn test_item_file_condition_with_undeclared_variable() {
+        let item = Item {
+            template: "templates/components.hbs".to_string(),
+            file_extension: "tsx".to_string(),
+            files: vec![ItemFile {
+                template: "templates/styles.hbs".to_string(),
+                file_extension: "styles.ts".to_string(),
+                when: Some("withStyles".to_string()),
+            }],
+            ..Default::default()
+        };
+
+        let error_msg = item
+            .validate("modules", "components")
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("undeclared variable 'withStyles'"));
+    }
+
+    #[test]
+    fn test_nested_categories_validation() {
         let config_json = r#"
         {
             "project": {
@@ -289,12 +1775,27 @@ mod tests {
                 "version": "1.0",
                 "structure": {
                     "features": {
-                        "description": "Dynamic features",
                         "allow_dynamic_children": true,
-                        "default_structure": {
-                            "modules": {
-                                "template": "templates/module.hbs",
-                                "file_extension": "tsx"
+                        "module_structure": {
+                            "allow_dynamic_children": true,
+                            "module_structure": {
+                                "categories": {
+                                    "ui": {
+                                        "children": {
+                                            "components": {
+                                                "template": "templates/components.hbs",
+                                                "file_extension": "tsx"
+                                            }
+                                        }
+                                    }
+                                },
+                                "default_structure": {
+                                    "hooks": {
+                                        "template": "templates/hooks.hbs",
+                                        "file_extension": "ts"
+                                    }
+                                },
+                                "allow_dynamic_children": false
                             }
                         }
                     }
@@ -304,35 +1805,57 @@ mod tests {
         "#;
 
         let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
+        let error_msg = config.validate().unwrap_err().to_string();
+        assert!(error_msg.contains("features/<module>/<module>"));
+        assert!(error_msg.contains("dynamic children are not enabled"));
+
+        let valid_json = config_json.replace(
+            r#""default_structure": {
+                                    "hooks": {
+                                        "template": "templates/hooks.hbs",
+                                        "file_extension": "ts"
+                                    }
+                                },
+                                "allow_dynamic_children": false"#,
+            r#""children": {
+                                    "hooks": {
+                                        "template": "templates/hooks.hbs",
+                                        "file_extension": "ts"
+                                    }
+                                }"#,
+        );
+        let config: ProjectConfig = serde_json::from_str(&valid_json).unwrap();
         config.validate().unwrap();
 
         let features = config.get_category("features").unwrap();
-        assert!(features.supports_dynamic_children());
-        assert!(features.get_default_structure().is_some());
+        let subdomain = features
+            .get_module_structure()
+            .and_then(|domain| domain.get_module_structure())
+            .unwrap();
+        assert_eq!(subdomain.get_subcategory_names(), vec!["ui"]);
+        assert!(subdomain.get_item("hooks").is_some());
     }
 
     #[test]
-    fn test_mixed_category_validation() {
+    fn test_declaration_order_is_preserved() {
         let config_json = r#"
         {
             "project": {
                 "name": "test-project",
                 "version": "1.0",
                 "structure": {
-                    "features": {
-                        "description": "Mixed features",
+                    "pages": {
                         "children": {
-                            "existing": {
-                                "template": "templates/existing.hbs",
-                                "file_extension": "tsx"
-                            }
-                        },
+                            "settings": { "template": "t.hbs", "file_extension": "tsx" },
+                            "dashboard": { "template": "t.hbs", "file_extension": "tsx" }
+                        }
+                    },
+                    "modules": {
                         "allow_dynamic_children": true,
                         "default_structure": {
-                            "modules": {
-                                "template": "templates/module.hbs",
-                                "file_extension": "tsx"
-                            }
+                            "services": { "template": "t.hbs", "file_extension": "ts" },
+                            "components": { "template": "t.hbs", "file_extension": "tsx" },
+                            "hooks": { "template": "t.hbs", "file_extension": "ts" }
                         }
                     }
                 }
@@ -341,7 +1864,19 @@ mod tests {
         "#;
 
         let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
-        config.validate().unwrap();
+        assert_eq!(config.get_categories(), vec!["pages", "modules"]);
+
+        let pages = config.get_category("pages").unwrap();
+        assert_eq!(pages.get_item_names(), vec!["settings", "dashboard"]);
+
+        let modules = config.get_category("modules").unwrap();
+        let dynamic_types: Vec<&String> = modules.get_default_structure().unwrap().keys().collect();
+        assert_eq!(dynamic_types, vec!["services", "components", "hooks"]);
+
+        // Order survives a round trip
+        let reparsed: ProjectConfig =
+            serde_json::from_str(&serde_json::to_string(&config).unwrap()).unwrap();
+        assert_eq!(reparsed.get_categories(), vec!["pages", "modules"]);
     }
 
     #[test]
@@ -368,10 +1903,364 @@ mod tests {
         let temp_file = NamedTempFile::new().unwrap();
         fs::write(temp_file.path(), config_json).unwrap();
 
-        let config = ProjectConfig::load_and_validate(&temp_file.path().to_path_buf()).unwrap();
+        let config = ProjectConfig::load_and_validate(temp_file.path()).unwrap();
         assert_eq!(config.project.name, "test-project");
     }
 
+    #[test]
+    fn test_extends_merges_and_overrides() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let base_path = temp_dir.path().join("base.json");
+        fs::write(
+            &base_path,
+            r#"
+            {
+                "project": {
+                    "name": "shared",
+                    "version": "1.0",
+                    "structure": {
+                        "modules": {
+                            "allow_dynamic_children": true,
+                            "default_structure": {
+                                "components": { "template": "templates/components.hbs", "file_extension": "tsx" },
+                                "hooks": { "template": "templates/hooks.hbs", "file_extension": "ts" }
+                            }
+                        },
+                        "pages": {
+                            "children": {
+                                "dashboard": { "template": "templates/page.hbs", "file_extension": "tsx" }
+                            }
+                        }
+                    }
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let config_path = temp_dir.path().join("mobile.json");
+        fs::write(
+            &config_path,
+            r#"
+            {
+                "extends": "base.json",
+                "project": {
+                    "name": "mobile",
+                    "structure": {
+                        "modules": {
+                            "default_structure": {
+                                "components": { "file_extension": "native.tsx" },
+                                "hooks": null
+                            }
+                        },
+                        "pages": null,
+                        "screens": {
+                            "children": {
+                                "home": { "template": "templates/screen.hbs", "file_extension": "tsx" }
+                            }
+                        }
+                    }
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let config = ProjectConfig::load_and_validate(&config_path).unwrap();
+        assert_eq!(config.extends.as_deref(), Some("base.json"));
+        assert_eq!(config.project.name, "mobile");
+        assert_eq!(config.project.version, "1.0");
+        assert_eq!(config.get_categories(), vec!["modules", "screens"]);
+
+        let structure = config
+            .get_category("modules")
+            .unwrap()
+            .get_default_structure()
+            .unwrap();
+        assert_eq!(structure.len(), 1);
+        // The base's template path is relative to the base file
+        assert_eq!(
+            PathBuf::from(&structure["components"].template),
+            temp_dir.path().join("templates/components.hbs")
+        );
+        assert_eq!(structure["components"].file_extension, "native.tsx");
+    }
+
+    #[test]
+    fn test_extends_records_templates_dir_source() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let base_path = temp_dir.path().join("base.json");
+        let config_path = temp_dir.path().join("web.json");
+        fs::write(
+            &base_path,
+            r#"{ "project": { "name": "shared", "version": "1.0", "templates_dir": "partials",
+                "structure": { "pages": { "children": {
+                    "home": { "template": "page.hbs", "file_extension": "tsx" }
+                } } } } }"#,
+        )
+        .unwrap();
+        fs::write(&config_path, r#"{ "extends": "base.json", "project": {} }"#).unwrap();
+
+        let config = ProjectConfig::load_and_validate(&config_path).unwrap();
+        assert_eq!(config.templates_dir_source, Some(base_path.clone()));
+
+        fs::write(
+            &config_path,
+            r#"{ "extends": "base.json", "project": { "templates_dir": "shared" } }"#,
+        )
+        .unwrap();
+        let config = ProjectConfig::load_and_validate(&config_path).unwrap();
+        assert_eq!(config.templates_dir_source, Some(config_path.clone()));
+
+        fs::write(
+            &config_path,
+            r#"{ "extends": "base.json", "project": { "templates_dir": null } }"#,
+        )
+        .unwrap();
+        let config = ProjectConfig::load_and_validate(&config_path).unwrap();
+        assert_eq!(config.templates_dir(), None);
+        assert_eq!(config.templates_dir_source, None);
+    }
+
+    #[test]
+    fn test_extends_errors_point_at_declaring_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let base_path = temp_dir.path().join("base.json");
+        let config_path = temp_dir.path().join("web.json");
+
+        // The extending file breaks a valid base
+        fs::write(
+            &base_path,
+            r#"{ "project": { "name": "shared", "version": "1.0", "structure": {
+                "pages": { "children": { "dashboard": { "template": "t.hbs", "file_extension": "tsx" } } }
+            } } }"#,
+        )
+        .unwrap();
+        fs::write(
+            &config_path,
+            r#"{ "extends": "base.json", "project": { "structure": {
+                "modules": { "allow_dynamic_children": true }
+            } } }"#,
+        )
+        .unwrap();
+        let error_msg = ProjectConfig::load_and_validate(&config_path)
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("allows dynamic children but has no default structure"));
+        assert!(error_msg.contains("web.json:2:30: "), "{}", error_msg);
+        assert!(error_msg.contains("project.structure.modules.allow_dynamic_children: Category"));
+
+        // An incomplete base is fine, but a broken item in it is blamed on the base
+        fs::write(
+            &base_path,
+            r#"{ "project": { "structure": {
+                "pages": { "children": { "dashboard": { "template": "", "file_extension": "tsx" } } }
+            } } }"#,
+        )
+        .unwrap();
+        fs::write(
+            &config_path,
+            r#"{ "extends": "base.json", "project": { "name": "web", "version": "1.0" } }"#,
+        )
+        .unwrap();
+        let error_msg = ProjectConfig::load_and_validate(&config_path)
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("base.json:2:57: "), "{}", error_msg);
+        assert!(error_msg.contains("project.structure.pages.children.dashboard.template: Item"));
+    }
+
+    #[test]
+    fn test_category_layout_explanations() {
+        let items = || {
+            let mut items = IndexMap::new();
+            items.insert(
+                "hooks".to_string(),
+                Item {
+                    template: "t.hbs".to_string(),
+                    file_extension: "ts".to_string(),
+                    ..Default::default()
+                },
+            );
+            Some(items)
+        };
+
+        let cases = vec![
+            (
+                None,
+                None,
+                None,
+                "must have either children or dynamic support",
+            ),
+            (Some(IndexMap::new()), None, None, "has empty children"),
+            (
+                None,
+                Some(false),
+                None,
+                "dynamic children disabled but no static children",
+            ),
+            (
+                None,
+                Some(false),
+                items(),
+                "default_structure is never used",
+            ),
+            (None, Some(true), None, "has no default structure"),
+            (items(), Some(true), None, "has no default structure"),
+            (
+                None,
+                Some(true),
+                Some(IndexMap::new()),
+                "has empty default structure",
+            ),
+            (None, None, items(), "allow_dynamic_children is not set"),
+            (items(), None, items(), "allow_dynamic_children is not set"),
+            (
+                items(),
+                Some(false),
+                items(),
+                "dynamic children are disabled",
+            ),
+            (items(), Some(false), None, "which is already the default"),
+        ];
+
+        for (children, allow_dynamic_children, default_structure, expected) in cases {
+            let category = Category {
+                children,
+                allow_dynamic_children,
+                default_structure,
+                ..Default::default()
+            };
+            let error_msg = category.validate("features").unwrap_err().to_string();
+            assert!(error_msg.contains(expected), "{}", error_msg);
+            assert!(error_msg.contains("💡"), "{}", error_msg);
+        }
+    }
+
+    #[test]
+    fn test_validation_reports_every_error_with_location() {
+        let temp_file = NamedTempFile::new().unwrap();
+        fs::write(
+            temp_file.path(),
+            r#"{
+  "project": {
+    "name": "",
+    "version": "1.0",
+    "structure": {
+      "features": {
+        "allow_dynamic_children": true,
+        "default_structure": {
+          "hooks": { "template": "", "file_extension": "ts" }
+        }
+      },
+      "pages": { "allow_dynamic_children": true }
+    }
+  }
+}"#,
+        )
+        .unwrap();
+
+        let error_msg = ProjectConfig::load_and_validate(temp_file.path())
+            .unwrap_err()
+            .to_string();
+        let file = temp_file.path().display();
+        assert!(error_msg.starts_with("Found 3 problems in the config:"));
+        assert!(error_msg.contains(&format!(
+            "{}:3:5: project.name: Project name cannot be empty",
+            file
+        )));
+        assert!(error_msg.contains(&format!(
+            "{}:9:22: project.structure.features.default_structure.hooks.template: Item 'hooks' in category 'features' has empty template path",
+            file
+        )));
+        assert!(error_msg.contains(&format!(
+            "{}:12:18: project.structure.pages.allow_dynamic_children: Category 'pages' allows dynamic children but has no default structure",
+            file
+        )));
+
+        // Type errors are located too
+        fs::write(
+            temp_file.path(),
+            r#"{ "project": { "name": "app", "version": "1.0", "structure": {
+    "pages": { "children": { "home": { "template": "t.hbs", "file_extension": 5 } } }
+} } }"#,
+        )
+        .unwrap();
+        let error_msg = ProjectConfig::load_and_validate(temp_file.path())
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Failed to parse config JSON: invalid type: integer `5`"));
+        assert!(error_msg
+            .contains("project.structure.pages.children.home.file_extension: Failed to parse"));
+        assert!(
+            error_msg.contains(&format!("{}:2:61", file)),
+            "{}",
+            error_msg
+        );
+    }
+
+    #[test]
+    fn test_extends_builtin_preset() {
+        let temp_file = NamedTempFile::new().unwrap();
+        fs::write(
+            temp_file.path(),
+            r#"{ "extends": "module-based", "project": { "name": "my-app" } }"#,
+        )
+        .unwrap();
+
+        let config = ProjectConfig::load_and_validate(temp_file.path()).unwrap();
+        let preset =
+            ProjectConfig::load_and_validate(&PathBuf::from("config-module-based.json")).unwrap();
+        assert_eq!(config.project.name, "my-app");
+        assert_eq!(config.get_categories(), preset.get_categories());
+    }
+
+    #[test]
+    fn test_load_older_schema_version() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let unversioned = r#"{ "project": { "name": "app", "version": "1.0", "structure": {
+            "pages": { "children": { "home": { "template": "t.hbs", "file_extension": ".tsx" } } },
+            "modules": { "allow_dynamic_children": true, "default_structure": {
+                "hooks": { "template": "t.hbs", "file_extension": "ts" } } }
+        } } }"#;
+        fs::write(temp_file.path(), unversioned).unwrap();
+
+        // Older configs are upgraded in memory, keeping their declaration order
+        let config = ProjectConfig::load_and_validate(temp_file.path()).unwrap();
+        assert_eq!(config.schema_version, Some(CURRENT_SCHEMA_VERSION));
+        assert_eq!(config.get_categories(), vec!["pages", "modules"]);
+        assert_eq!(
+            config
+                .get_category("pages")
+                .unwrap()
+                .get_item("home")
+                .unwrap()
+                .file_extension,
+            "tsx"
+        );
+
+        // The current schema rejects what the migration would have fixed
+        fs::write(
+            temp_file.path(),
+            unversioned.replacen("{", r#"{ "schema_version": 2,"#, 1),
+        )
+        .unwrap();
+        let error_msg = ProjectConfig::load_and_validate(temp_file.path())
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("'.tsx' starting with a dot"));
+
+        fs::write(
+            temp_file.path(),
+            unversioned.replacen("{", r#"{ "schema_version": 3,"#, 1),
+        )
+        .unwrap();
+        let error_msg = ProjectConfig::load_and_validate(temp_file.path())
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("newer than this version of Creator supports"));
+    }
+
     #[test]
     fn test_clean_architecture_config_example() {
         let config =
@@ -0,0 +1,351 @@
+use anyhow::Result;
+use std::fmt;
+
+use crate::extends::ConfigLayer;
+
+/// One problem found in a config, located by its JSON path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON path of the offending value, e.g. `project.structure.features.default_structure.hooks.template`
+    pub path: String,
+    pub message: String,
+    /// `file:line:column` of the value, when the config was loaded from files
+    pub location: Option<String>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "{}: ", location)?;
+        }
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Every problem found in a config, reported together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationErrors {
+    pub errors: Vec<ValidationError>,
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.errors.as_slice() {
+            [error] => write!(f, "{}", error),
+            errors => {
+                write!(f, "Found {} problems in the config:", errors.len())?;
+                for error in errors {
+                    write!(f, "\n  • {}", error)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl ValidationErrors {
+    /// A single error at a JSON path
+    pub fn at(path: &str, message: impl fmt::Display) -> Self {
+        ValidationErrors {
+            errors: vec![ValidationError {
+                path: path.to_string(),
+                message: message.to_string(),
+                location: None,
+            }],
+        }
+    }
+
+    /// Point each error at the file, line and column that declares its path. The most
+    /// specific layer declaring the deepest part of the path wins.
+    pub fn locate(&mut self, layers: &[ConfigLayer]) {
+        let positions: Vec<(&str, Vec<KeyPosition>)> = layers
+            .iter()
+            .map(|layer| (layer.source.as_str(), key_positions(&layer.contents)))
+            .collect();
+
+        for error in &mut self.errors {
+            let mut best: Option<(usize, &str, &KeyPosition)> = None;
+            for (source, keys) in &positions {
+                for key in keys {
+                    let matches = error.path == key.path
+                        || error.path.starts_with(&format!("{}.", key.path))
+                        || error.path.starts_with(&format!("{}[", key.path));
+                    // Later layers override earlier ones, so they win ties
+                    if matches && best.is_none_or(|(depth, _, _)| key.path.len() >= depth) {
+                        best = Some((key.path.len(), source, key));
+                    }
+                }
+            }
+
+            if let Some((_, source, key)) = best {
+                error.location = Some(format!("{}:{}:{}", source, key.line, key.column));
+            }
+        }
+    }
+}
+
+/// Collects validation errors so a whole config can be checked in one pass
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    errors: Vec<ValidationError>,
+}
+
+impl Diagnostics {
+    /// Record a problem at a JSON path
+    pub fn push(&mut self, path: &str, message: impl fmt::Display) {
+        self.errors.push(ValidationError {
+            path: path.to_string(),
+            message: message.to_string(),
+            location: None,
+        });
+    }
+
+    /// Record the error of a check, if it failed
+    pub fn check(&mut self, path: &str, result: Result<()>) {
+        if let Err(error) = result {
+            self.push(path, error);
+        }
+    }
+
+    /// Fail with every recorded problem, or succeed if there were none
+    pub fn into_result(self) -> Result<()> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors {
+                errors: self.errors,
+            }
+            .into())
+        }
+    }
+}
+
+/// Join a JSON path and an object key
+pub fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Where a key or array element starts in a JSON document
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct KeyPosition {
+    path: String,
+    line: usize,
+    column: usize,
+}
+
+/// Find the position of every key and array element of a JSON document.
+/// Malformed input is scanned as far as it goes.
+fn key_positions(contents: &str) -> Vec<KeyPosition> {
+    let mut scanner = Scanner {
+        chars: contents.chars().peekable(),
+        line: 1,
+        column: 1,
+        positions: Vec::new(),
+    };
+    scanner.value("");
+    scanner.positions
+}
+
+struct Scanner<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+    positions: Vec<KeyPosition>,
+}
+
+impl Scanner<'_> {
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    fn record(&mut self, path: String) {
+        self.positions.push(KeyPosition {
+            path,
+            line: self.line,
+            column: self.column,
+        });
+    }
+
+    fn value(&mut self, path: &str) {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('{') => self.object(path),
+            Some('[') => self.array(path),
+            Some('"') => {
+                self.string();
+            }
+            Some(_) => {
+                while self
+                    .chars
+                    .peek()
+                    .is_some_and(|c| !matches!(c, ',' | '}' | ']') && !c.is_whitespace())
+                {
+                    self.bump();
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn object(&mut self, path: &str) {
+        self.bump();
+        loop {
+            self.skip_whitespace();
+            if self.chars.peek() != Some(&'"') {
+                self.bump();
+                return;
+            }
+
+            let (line, column) = (self.line, self.column);
+            let key = self.string();
+            let key_path = child_path(path, &key);
+            self.positions.push(KeyPosition {
+                path: key_path.clone(),
+                line,
+                column,
+            });
+
+            self.skip_whitespace();
+            if self.bump() != Some(':') {
+                return;
+            }
+            self.value(&key_path);
+
+            self.skip_whitespace();
+            if self.bump() != Some(',') {
+                return;
+            }
+        }
+    }
+
+    fn array(&mut self, path: &str) {
+        self.bump();
+        let mut index = 0;
+        loop {
+            self.skip_whitespace();
+            if matches!(self.chars.peek(), Some(']') | None) {
+                self.bump();
+                return;
+            }
+
+            let element_path = format!("{}[{}]", path, index);
+            self.record(element_path.clone());
+            self.value(&element_path);
+
+            self.skip_whitespace();
+            if self.bump() != Some(',') {
+                return;
+            }
+            index += 1;
+        }
+    }
+
+    fn string(&mut self) -> String {
+        self.bump();
+        let mut contents = String::new();
+        while let Some(c) = self.bump() {
+            match c {
+                '"' => break,
+                '\\' => {
+                    if let Some(escaped) = self.bump() {
+                        contents.push(escaped);
+                    }
+                }
+                c => contents.push(c),
+            }
+        }
+        contents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn layer(source: &str, contents: &str) -> ConfigLayer {
+        ConfigLayer {
+            source: source.to_string(),
+            extends: None,
+            value: Value::Null,
+            contents: contents.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_key_positions() {
+        let positions = key_positions(
+            "{\n  \"project\": {\n    \"files\": [\n      { \"template\": \"a\\\"b\" }\n    ]\n  }\n}",
+        );
+        let paths: Vec<(&str, usize, usize)> = positions
+            .iter()
+            .map(|p| (p.path.as_str(), p.line, p.column))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("project", 2, 3),
+                ("project.files", 3, 5),
+                ("project.files[0]", 4, 7),
+                ("project.files[0].template", 4, 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_locate_prefers_deepest_and_latest_layer() {
+        let base = layer(
+            "base.json",
+            "{ \"project\": { \"structure\": {\n  \"pages\": { \"children\": {} } } } }",
+        );
+        let web = layer(
+            "web.json",
+            "{ \"project\": {\n  \"structure\": {\n    \"modules\": {} } } }",
+        );
+
+        let mut errors = ValidationErrors {
+            errors: vec![
+                ValidationError {
+                    path: "project.structure.pages.children".to_string(),
+                    message: "empty children".to_string(),
+                    location: None,
+                },
+                ValidationError {
+                    path: "project.structure.modules.default_structure".to_string(),
+                    message: "no default structure".to_string(),
+                    location: None,
+                },
+            ],
+        };
+        errors.locate(&[base, web]);
+
+        assert_eq!(errors.errors[0].location.as_deref(), Some("base.json:2:14"));
+        assert_eq!(errors.errors[1].location.as_deref(), Some("web.json:3:5"));
+        assert_eq!(
+            errors.to_string(),
+            "Found 2 problems in the config:\n  • base.json:2:14: project.structure.pages.children: empty children\n  • web.json:3:5: project.structure.modules.default_structure: no default structure"
+        );
+    }
+}
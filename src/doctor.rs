@@ -0,0 +1,327 @@
+use anyhow::{anyhow, Result};
+use handlebars::Template;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::config::ProjectConfig;
+use crate::file_utils::list_subdirectories;
+use crate::generator::Generator;
+use crate::git;
+use crate::validation::validate_name;
+
+/// How serious a doctor finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Ok,
+    /// Worth fixing, but creates still work
+    Warning,
+    /// Breaks creates
+    Problem,
+}
+
+/// One result of `creator doctor`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Finding {
+    pub fn ok(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Ok,
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+        }
+    }
+
+    pub fn problem(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Problem,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let icon = match self.severity {
+            Severity::Ok => "✅",
+            Severity::Warning => "⚠️ ",
+            Severity::Problem => "❌",
+        };
+        write!(f, "{} {}", icon, self.message)
+    }
+}
+
+/// Check a loaded project: its source directory, templates, module folders and git
+/// setup. `project_dir` is where hooks and git run.
+pub fn check_project(
+    config: &ProjectConfig,
+    source_dir: &Path,
+    project_dir: &Path,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    if source_dir.is_dir() {
+        findings.push(Finding::ok(format!(
+            "Source directory {} exists",
+            source_dir.display()
+        )));
+    } else {
+        findings.push(Finding::problem(format!(
+            "Source directory {} not found. 💡 Create it or pass --source_dir",
+            source_dir.display()
+        )));
+    }
+
+    check_templates(config, &mut findings);
+    check_modules(config, source_dir, &mut findings);
+    check_git(config, project_dir, &mut findings);
+
+    findings
+}
+
+/// Print the findings and fail when any of them is a problem
+pub fn report(findings: &[Finding]) -> Result<()> {
+    println!("🩺 Checking the project...");
+    for finding in findings {
+        println!("   {}", finding);
+    }
+    println!();
+
+    let count = |severity| {
+        findings
+            .iter()
+            .filter(|finding| finding.severity == severity)
+            .count()
+    };
+    let (problems, warnings) = (count(Severity::Problem), count(Severity::Warning));
+    if problems > 0 {
+        return Err(anyhow!(
+            "Doctor found {} problem(s) and {} warning(s)",
+            problems,
+            warnings
+        ));
+    }
+
+    println!("No problems found ({} warning(s))", warnings);
+    Ok(())
+}
+
+/// Shared partials and personal overrides load, and every declared template exists
+/// and compiles
+fn check_templates(config: &ProjectConfig, findings: &mut Vec<Finding>) {
+    if let Some(dir) = config.templates_dir() {
+        let generator = Generator::new(Some(&dir)).and_then(|generator| {
+            if let Some(overrides) = &config.template_overrides {
+                generator.register_overrides(overrides)?;
+            }
+            Ok(generator)
+        });
+        match generator {
+            Ok(generator) => findings.push(Finding::ok(format!(
+                "Templates directory {} has {} partial(s)",
+                dir.display(),
+                generator.partial_names().len()
+            ))),
+            Err(e) => findings.push(Finding::problem(e.to_string())),
+        }
+    }
+
+    let templates = config.declared_templates();
+    let mut broken = 0;
+    for template in &templates {
+        let source = match fs::read_to_string(template) {
+            Ok(source) => source,
+            Err(e) => {
+                broken += 1;
+                findings.push(Finding::problem(format!(
+                    "Template {} can't be read: {}",
+                    template.display(),
+                    e
+                )));
+                continue;
+            }
+        };
+        if let Err(e) = Template::compile(&source) {
+            broken += 1;
+            findings.push(Finding::problem(format!(
+                "Template {} doesn't compile: {}",
+                template.display(),
+                e
+            )));
+        }
+    }
+    if broken == 0 {
+        findings.push(Finding::ok(format!(
+            "{} template(s) found and compile",
+            templates.len()
+        )));
+    }
+}
+
+/// Existing module folders follow the module naming rules
+fn check_modules(config: &ProjectConfig, source_dir: &Path, findings: &mut Vec<Finding>) {
+    let rules = config.module_validation();
+    let mut checked = 0;
+    let mut invalid = 0;
+
+    for category_name in config.get_categories() {
+        let dynamic = config
+            .get_category(&category_name)
+            .is_some_and(|category| category.supports_dynamic_children());
+        if !dynamic {
+            continue;
+        }
+
+        for module in list_subdirectories(&source_dir.join(&category_name)) {
+            checked += 1;
+            if let Err(message) = validate_name("module", &module, &rules, &[]) {
+                invalid += 1;
+                findings.push(Finding::warning(format!(
+                    "{}/{}: {}. 💡 Rename the folder",
+                    category_name, module, message
+                )));
+            }
+        }
+    }
+
+    if checked > 0 && invalid == 0 {
+        findings.push(Finding::ok(format!(
+            "{} module folder(s) follow the naming rules",
+            checked
+        )));
+    }
+}
+
+/// The project is inside a git repository when the git options need one
+fn check_git(config: &ProjectConfig, project_dir: &Path, findings: &mut Vec<Finding>) {
+    let Some(options) = &config.project.git else {
+        return;
+    };
+    let uses_git = [options.require_clean, options.stage, options.commit]
+        .into_iter()
+        .any(|option| option.unwrap_or(false));
+    if !uses_git {
+        return;
+    }
+
+    match git::ensure_repository(project_dir) {
+        Ok(()) => findings.push(Finding::ok("Git repository found for the git options")),
+        Err(e) => findings.push(Finding::problem(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Category, Item, NameValidation, ProjectInfo, ProjectValidation};
+    use indexmap::IndexMap;
+    use tempfile::TempDir;
+
+    fn test_config(temp_dir: &Path) -> ProjectConfig {
+        let item = |template: &str| Item {
+            template: temp_dir.join(template).to_string_lossy().to_string(),
+            file_extension: "ts".to_string(),
+            ..Default::default()
+        };
+        let mut default_structure = IndexMap::new();
+        default_structure.insert("services".to_string(), item("good.hbs"));
+        default_structure.insert("hooks".to_string(), item("broken.hbs"));
+        default_structure.insert("utils".to_string(), item("missing.hbs"));
+
+        let mut structure = IndexMap::new();
+        structure.insert(
+            "modules".to_string(),
+            Category {
+                allow_dynamic_children: Some(true),
+                default_structure: Some(default_structure),
+                ..Default::default()
+            },
+        );
+
+        ProjectConfig {
+            project: ProjectInfo {
+                name: "doctor".to_string(),
+                version: "1.0".to_string(),
+                structure,
+                validation: Some(ProjectValidation {
+                    module: Some(NameValidation {
+                        max_length: Some(8),
+                        ..Default::default()
+                    }),
+                    item: None,
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_check_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::write(dir.join("good.hbs"), "export const {{templateName}} = 1;").unwrap();
+        fs::write(dir.join("broken.hbs"), "{{#if}}").unwrap();
+        let source_dir = dir.join("src");
+        fs::create_dir_all(source_dir.join("modules/cats")).unwrap();
+        fs::create_dir_all(source_dir.join("modules/much-too-long")).unwrap();
+
+        let findings = check_project(&test_config(dir), &source_dir, dir);
+        let problems: Vec<&str> = findings
+            .iter()
+            .filter(|finding| finding.severity == Severity::Problem)
+            .map(|finding| finding.message.as_str())
+            .collect();
+        assert_eq!(problems.len(), 2);
+        assert!(problems[0].contains("broken.hbs doesn't compile"));
+        assert!(problems[1].contains("missing.hbs can't be read"));
+
+        let warnings: Vec<&Finding> = findings
+            .iter()
+            .filter(|finding| finding.severity == Severity::Warning)
+            .collect();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0]
+            .message
+            .starts_with("modules/much-too-long: Invalid module name"));
+
+        let error_msg = report(&findings).unwrap_err().to_string();
+        assert_eq!(error_msg, "Doctor found 2 problem(s) and 1 warning(s)");
+    }
+
+    #[test]
+    fn test_check_project_without_problems() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        for template in ["good.hbs", "broken.hbs", "missing.hbs"] {
+            fs::write(dir.join(template), "{{templateName}}").unwrap();
+        }
+
+        // A missing source directory is a problem of its own
+        let findings = check_project(&test_config(dir), &dir.join("src"), dir);
+        assert_eq!(
+            findings
+                .iter()
+                .filter(|finding| finding.severity != Severity::Ok)
+                .count(),
+            1
+        );
+
+        fs::create_dir_all(dir.join("src")).unwrap();
+        let findings = check_project(&test_config(dir), &dir.join("src"), dir);
+        assert!(findings
+            .iter()
+            .all(|finding| finding.severity == Severity::Ok));
+        assert!(report(&findings).is_ok());
+    }
+}
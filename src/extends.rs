@@ -0,0 +1,373 @@
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::migrate::migrate;
+
+/// Presets shipped with the binary, usable by name in `extends`
+pub const BUILTIN_PRESETS: &[(&str, &str)] = &[
+    (
+        "clean-architecture",
+        include_str!("../config-clean-architecture.json"),
+    ),
+    ("module-based", include_str!("../config-module-based.json")),
+];
+
+/// Get the contents of a built-in preset by name
+pub fn builtin_preset(name: &str) -> Option<&'static str> {
+    BUILTIN_PRESETS
+        .iter()
+        .find(|(preset, _)| *preset == name)
+        .map(|(_, contents)| *contents)
+}
+
+/// One config file of an `extends` chain
+#[derive(Debug)]
+pub struct ConfigLayer {
+    /// Where the layer came from: a file path or `preset '<name>'`
+    pub source: String,
+    /// The `extends` value declared by this layer, if any
+    pub extends: Option<String>,
+    /// The layer's JSON without its `extends` field, upgraded to the current schema
+    pub value: Value,
+    /// The layer's original text, used to locate errors
+    pub contents: String,
+}
+
+/// Load a config file and every config it extends, base first
+pub fn load_layers(config_path: &Path) -> Result<Vec<ConfigLayer>> {
+    let mut layers = Vec::new();
+    let mut chain = Vec::new();
+    load_file_layer(config_path, &mut chain, &mut layers)?;
+    Ok(layers)
+}
+
+fn load_file_layer(
+    path: &Path,
+    chain: &mut Vec<PathBuf>,
+    layers: &mut Vec<ConfigLayer>,
+) -> Result<()> {
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if chain.contains(&canonical) {
+        let cycle: Vec<String> = chain
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|path| path.display().to_string())
+            .collect();
+        return Err(anyhow!("Circular extends: {}", cycle.join(" -> ")));
+    }
+
+    let contents =
+        fs::read_to_string(path).map_err(|e| anyhow!("Failed to read config file: {}", e))?;
+    let source = path.display().to_string();
+
+    // Files pulled in through `extends` declare paths relative to themselves
+    let extended = !chain.is_empty();
+    chain.push(canonical);
+    let base_dir = path.parent().unwrap_or(Path::new(""));
+    load_layer(&contents, source, Some(base_dir), chain, layers)?;
+    chain.pop();
+
+    if extended {
+        if let Some(layer) = layers.last_mut() {
+            rebase_template_paths(&mut layer.value, base_dir);
+        }
+    }
+
+    Ok(())
+}
+
+/// Make the relative template paths and `templates_dir` of an extended file point
+/// into that file's directory, so they keep working from the extending config
+fn rebase_template_paths(value: &mut Value, dir: &Path) {
+    let Some(project) = value.get_mut("project") else {
+        return;
+    };
+
+    if let Some(templates_dir) = project.get_mut("templates_dir") {
+        rebase_path(templates_dir, dir);
+    }
+    if let Some(structure) = project.get_mut("structure") {
+        rebase_templates(structure, dir);
+    }
+}
+
+/// Rebase every `template` string found anywhere under `value`
+fn rebase_templates(value: &mut Value, dir: &Path) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if key == "template" {
+                    rebase_path(value, dir);
+                } else {
+                    rebase_templates(value, dir);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                rebase_templates(value, dir);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn rebase_path(value: &mut Value, dir: &Path) {
+    if let Value::String(path) = value {
+        if !path.is_empty() && Path::new(path.as_str()).is_relative() {
+            let joined = dir.join(path.as_str());
+            let rebased = std::path::absolute(&joined).unwrap_or(joined);
+            *path = rebased.to_string_lossy().into_owned();
+        }
+    }
+}
+
+fn load_layer(
+    contents: &str,
+    source: String,
+    base_dir: Option<&Path>,
+    chain: &mut Vec<PathBuf>,
+    layers: &mut Vec<ConfigLayer>,
+) -> Result<()> {
+    let mut value: Value = serde_json::from_str(contents)
+        .map_err(|e| anyhow!("Failed to parse config JSON in {}: {}", source, e))?;
+
+    let extends = match value
+        .as_object_mut()
+        .and_then(|map| map.shift_remove("extends"))
+    {
+        None | Some(Value::Null) => None,
+        Some(Value::String(extends)) => Some(extends),
+        Some(_) => return Err(anyhow!("'extends' in {} must be a string", source)),
+    };
+
+    // Each file may be written in an older schema; upgrade it before merging
+    migrate(&mut value).map_err(|e| anyhow!("{} in {}", e, source))?;
+
+    // Load the base first so layers stay ordered from base to most specific
+    if let Some(extends) = &extends {
+        let candidate = base_dir
+            .map(|dir| dir.join(extends))
+            .unwrap_or_else(|| PathBuf::from(extends));
+
+        if candidate.is_file() {
+            load_file_layer(&candidate, chain, layers)?;
+        } else if let Some(preset) = builtin_preset(extends) {
+            load_layer(preset, format!("preset '{}'", extends), None, chain, layers)?;
+        } else {
+            let presets: Vec<&str> = BUILTIN_PRESETS.iter().map(|(name, _)| *name).collect();
+            return Err(anyhow!(
+                "Cannot extend '{}' from {}: no such file or built-in preset (available presets: {})",
+                extends,
+                source,
+                presets.join(", ")
+            ));
+        }
+    }
+
+    layers.push(ConfigLayer {
+        source,
+        extends,
+        value,
+        contents: contents.to_string(),
+    });
+
+    Ok(())
+}
+
+/// Deep-merge `overlay` into `base`: objects merge key by key, `null` removes
+/// the key, and any other value replaces the base value. Keys keep their declared order.
+pub fn merge_values(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                if value.is_null() {
+                    base.shift_remove(&key);
+                } else if let Some(existing) = base.get_mut(&key) {
+                    merge_values(existing, value);
+                } else {
+                    base.insert(key, value);
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Merge the first `count` layers into a single config value
+pub fn merge_layers(layers: &[ConfigLayer], count: usize) -> Value {
+    let mut merged = Value::Object(Default::default());
+    for layer in &layers[..count] {
+        merge_values(&mut merged, layer.value.clone());
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_merge_values() {
+        let mut base = json!({
+            "project": {
+                "name": "base",
+                "structure": {
+                    "modules": { "allow_dynamic_children": true, "description": "Modules" },
+                    "pages": { "children": {} }
+                }
+            }
+        });
+        let overlay = json!({
+            "project": {
+                "name": "web",
+                "structure": {
+                    "modules": { "description": "Feature modules" },
+                    "pages": null,
+                    "screens": { "children": {} }
+                }
+            }
+        });
+
+        merge_values(&mut base, overlay);
+
+        assert_eq!(
+            base,
+            json!({
+                "project": {
+                    "name": "web",
+                    "structure": {
+                        "modules": { "allow_dynamic_children": true, "description": "Feature modules" },
+                        "screens": { "children": {} }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_values_keeps_order_after_removal() {
+        let mut base = json!({
+            "structure": { "alpha": {}, "beta": {}, "gamma": {}, "delta": {} }
+        });
+        merge_values(
+            &mut base,
+            json!({ "structure": { "alpha": null, "epsilon": {} } }),
+        );
+
+        let categories: Vec<&String> = base["structure"].as_object().unwrap().keys().collect();
+        assert_eq!(categories, vec!["beta", "gamma", "delta", "epsilon"]);
+    }
+
+    #[test]
+    fn test_load_layers_follows_chain() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("shared")).unwrap();
+        std::fs::write(
+            temp_dir.path().join("shared/base.json"),
+            r#"{ "extends": "module-based", "project": { "name": "base" } }"#,
+        )
+        .unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        std::fs::write(
+            &config_path,
+            r#"{ "extends": "shared/base.json", "project": { "name": "web" } }"#,
+        )
+        .unwrap();
+
+        let layers = load_layers(&config_path).unwrap();
+        let sources: Vec<&str> = layers.iter().map(|layer| layer.source.as_str()).collect();
+        assert_eq!(sources.len(), 3);
+        assert_eq!(sources[0], "preset 'module-based'");
+        assert!(sources[1].ends_with("base.json"));
+        assert!(sources[2].ends_with("config.json"));
+        assert_eq!(layers[2].extends.as_deref(), Some("shared/base.json"));
+        assert!(layers[2].value.get("extends").is_none());
+
+        let merged = merge_layers(&layers, layers.len());
+        assert_eq!(merged["project"]["name"], "web");
+    }
+
+    #[test]
+    fn test_extended_template_paths_are_relative_to_their_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let shared = temp_dir.path().join("shared");
+        std::fs::create_dir_all(&shared).unwrap();
+        std::fs::write(
+            shared.join("base.json"),
+            r#"{
+                "project": {
+                    "name": "base",
+                    "templates_dir": "partials",
+                    "structure": {
+                        "modules": {
+                            "default_structure": {
+                                "components": {
+                                    "template": "templates/components.hbs",
+                                    "files": [{ "template": "templates/test.hbs" }]
+                                }
+                            }
+                        },
+                        "pages": { "children": { "screens": { "template": "/abs/screens.hbs" } } }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let app = temp_dir.path().join("app");
+        std::fs::create_dir_all(&app).unwrap();
+        let config_path = app.join("config.json");
+        std::fs::write(
+            &config_path,
+            r#"{
+                "extends": "../shared/base.json",
+                "project": { "structure": { "pages": { "children": { "own": { "template": "templates/own.hbs" } } } } }
+            }"#,
+        )
+        .unwrap();
+
+        let layers = load_layers(&config_path).unwrap();
+        let merged = merge_layers(&layers, layers.len());
+        let resolved = |value: &Value| PathBuf::from(value.as_str().unwrap());
+        let shared = std::path::absolute(app.join("../shared")).unwrap();
+
+        let project = &merged["project"];
+        assert_eq!(resolved(&project["templates_dir"]), shared.join("partials"));
+        let components = &project["structure"]["modules"]["default_structure"]["components"];
+        assert_eq!(
+            resolved(&components["template"]),
+            shared.join("templates/components.hbs")
+        );
+        assert_eq!(
+            resolved(&components["files"][0]["template"]),
+            shared.join("templates/test.hbs")
+        );
+
+        // Absolute paths and the extending config's own paths are kept as written
+        let pages = &project["structure"]["pages"]["children"];
+        assert_eq!(pages["screens"]["template"], "/abs/screens.hbs");
+        assert_eq!(pages["own"]["template"], "templates/own.hbs");
+    }
+
+    #[test]
+    fn test_load_layers_errors() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = temp_dir.path().join("config.json");
+        std::fs::write(&config_path, r#"{ "extends": "missing.json" }"#).unwrap();
+        let error_msg = load_layers(&config_path).unwrap_err().to_string();
+        assert!(error_msg.contains("Cannot extend 'missing.json'"));
+        assert!(error_msg.contains("clean-architecture, module-based"));
+
+        let a = temp_dir.path().join("a.json");
+        let b = temp_dir.path().join("b.json");
+        std::fs::write(&a, r#"{ "extends": "b.json" }"#).unwrap();
+        std::fs::write(&b, r#"{ "extends": "a.json" }"#).unwrap();
+        let error_msg = load_layers(&a).unwrap_err().to_string();
+        assert!(error_msg.contains("Circular extends"));
+    }
+}
@@ -1,6 +1,8 @@
 use anyhow::{anyhow, Result};
 use std::{fs, io::Write, path::Path};
 
+use crate::config::{CaseStyle, FileNameCase, NameRule};
+
 pub fn create_folder(folder_path: &Path) -> Result<()> {
     fs::create_dir_all(folder_path).map_err(|err| {
         anyhow!(
@@ -24,6 +26,21 @@ pub fn create_file(file_path: &Path, content: String) -> Result<usize> {
     })
 }
 
+/// List the names of the directories directly inside `path` (sorted, empty if missing)
+pub fn list_subdirectories(path: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().to_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
 pub fn to_kebab_case(input: &str) -> String {
     input
         .chars()
@@ -87,37 +104,111 @@ pub fn to_camel_case(input: &str) -> String {
     }
 }
 
-/// Generate template name based on item type and name
-pub fn generate_template_name(item_type: &str, name: &str) -> String {
+pub fn to_snake_case(input: &str) -> String {
+    to_kebab_case(input).replace('-', "_")
+}
+
+/// Convert a name to the given case style
+pub fn apply_case(style: CaseStyle, input: &str) -> String {
+    match style {
+        CaseStyle::Kebab => to_kebab_case(input),
+        CaseStyle::Pascal => to_pascal_case(input),
+        CaseStyle::Camel => to_camel_case(input),
+        CaseStyle::Snake => to_snake_case(input),
+        CaseStyle::Preserve => input.to_string(),
+    }
+}
+
+/// Apply a naming rule: strip the first matching prefix, convert case, then add prefix/suffix
+pub fn apply_name_rule(rule: &NameRule, name: &str, default_case: CaseStyle) -> String {
+    let clean_name = rule
+        .strip_prefixes
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix.as_str()))
+        .unwrap_or(name);
+
+    format!(
+        "{}{}{}",
+        rule.prefix.as_deref().unwrap_or_default(),
+        apply_case(rule.case.unwrap_or(default_case), clean_name),
+        rule.suffix.as_deref().unwrap_or_default()
+    )
+}
+
+/// Built-in template naming rule for well-known item types
+pub fn default_template_name_rule(item_type: &str) -> NameRule {
+    let pascal = NameRule {
+        case: Some(CaseStyle::Pascal),
+        ..Default::default()
+    };
+
     match item_type.to_lowercase().as_str() {
-        "hooks" => {
-            // For hooks: remove "use-" prefix if present, then PascalCase
-            let clean_name = if name.starts_with("use-") {
-                &name[4..] // Remove "use-" prefix
-            } else {
-                name
-            };
-            to_pascal_case(clean_name)
-        }
-        "components" | "containers" | "screens" | "pages" => {
-            // For components: PascalCase
-            to_pascal_case(name)
-        }
-        "services" => {
-            // For services: PascalCaseService
-            format!("{}Service", to_pascal_case(name))
-        }
-        "types" => {
-            // For types: PascalCaseType
-            format!("{}Type", to_pascal_case(name))
-        }
-        _ => {
-            // Default: PascalCase
-            to_pascal_case(name)
-        }
+        // For hooks: remove "use-" prefix if present, then PascalCase
+        "hooks" => NameRule {
+            strip_prefixes: vec!["use-".to_string()],
+            ..pascal
+        },
+        // For services: PascalCaseService
+        "services" => NameRule {
+            suffix: Some("Service".to_string()),
+            ..pascal
+        },
+        // For types: PascalCaseType
+        "types" => NameRule {
+            suffix: Some("Type".to_string()),
+            ..pascal
+        },
+        // Components, containers, screens, pages and everything else: PascalCase
+        _ => pascal,
     }
 }
 
+/// Generate template name based on item type and name using the built-in defaults
+pub fn generate_template_name(item_type: &str, name: &str) -> String {
+    resolve_template_name(item_type, None, name)
+}
+
+/// Generate template name from the item's own rule, falling back to the built-in defaults
+pub fn resolve_template_name(item_type: &str, rule: Option<&NameRule>, name: &str) -> String {
+    match rule {
+        Some(rule) => apply_name_rule(rule, name, CaseStyle::Pascal),
+        None => apply_name_rule(
+            &default_template_name_rule(item_type),
+            name,
+            CaseStyle::Pascal,
+        ),
+    }
+}
+
+/// Generate output file name (without extension).
+/// The item's naming rule wins; otherwise the file name case policy applies, kebab-case by default.
+pub fn resolve_file_name(
+    rule: Option<&NameRule>,
+    file_name_case: Option<&FileNameCase>,
+    name: &str,
+) -> String {
+    let default_case = match file_name_case {
+        Some(FileNameCase::Style(style)) => *style,
+        _ => CaseStyle::Kebab,
+    };
+
+    match (rule, file_name_case) {
+        (Some(rule), _) => apply_name_rule(rule, name, default_case),
+        (None, Some(FileNameCase::Pattern { pattern })) => apply_file_name_pattern(pattern, name),
+        (None, _) => apply_case(default_case, name),
+    }
+}
+
+/// Expand `{name}`, `{kebab}`, `{pascal}`, `{camel}` and `{snake}` placeholders in a file name pattern
+pub fn apply_file_name_pattern(pattern: &str, name: &str) -> String {
+    pattern
+        .replace("{name}", name)
+        .replace("{kebab}", &to_kebab_case(name))
+        .replace("{pascal}", &to_pascal_case(name))
+        .replace("{camel}", &to_camel_case(name))
+        .replace("{snake}", &to_snake_case(name))
+}
+
 /// Validates if a name contains only valid characters for file/directory names
 /// Allows: letters, numbers, hyphens, underscores
 /// Rejects: special characters like @, #, $, /, \, etc.
@@ -379,6 +470,116 @@ mod test {
         assert_eq!(generate_template_name("services", "x"), "XService");
     }
 
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("nav bar"), "nav_bar");
+        assert_eq!(to_snake_case("cat-list"), "cat_list");
+        assert_eq!(to_snake_case("Nav_Bar"), "nav_bar");
+    }
+
+    #[test]
+    fn test_resolve_template_name_with_item_rule() {
+        let rule = NameRule {
+            case: Some(CaseStyle::Pascal),
+            suffix: Some("Repository".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_template_name("repositories", Some(&rule), "cat-list"),
+            "CatListRepository"
+        );
+
+        let rule = NameRule {
+            case: Some(CaseStyle::Pascal),
+            prefix: Some("use".to_string()),
+            strip_prefixes: vec!["use-".to_string(), "use_".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_template_name("hooks", Some(&rule), "use_cat-list"),
+            "useCatList"
+        );
+
+        // Without a rule the built-in defaults apply
+        assert_eq!(
+            resolve_template_name("services", None, "cat-list"),
+            "CatListService"
+        );
+        assert_eq!(
+            resolve_template_name("viewmodels", None, "cat-list"),
+            "CatList"
+        );
+    }
+
+    #[test]
+    fn test_resolve_file_name() {
+        // Default: kebab-case
+        assert_eq!(resolve_file_name(None, None, "Cat List"), "cat-list");
+
+        let rule = NameRule {
+            case: Some(CaseStyle::Pascal),
+            suffix: Some(".screen".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_file_name(Some(&rule), None, "cat-list"),
+            "CatList.screen"
+        );
+
+        // Case defaults to kebab when the rule only sets affixes
+        let rule = NameRule {
+            prefix: Some("use-".to_string()),
+            strip_prefixes: vec!["use-".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_file_name(Some(&rule), None, "use-cat list"),
+            "use-cat-list"
+        );
+    }
+
+    #[test]
+    fn test_resolve_file_name_with_case_policy() {
+        let pascal = FileNameCase::Style(CaseStyle::Pascal);
+        let camel = FileNameCase::Style(CaseStyle::Camel);
+        let snake = FileNameCase::Style(CaseStyle::Snake);
+        let pattern = FileNameCase::Pattern {
+            pattern: "use{pascal}".to_string(),
+        };
+
+        assert_eq!(
+            resolve_file_name(None, Some(&pascal), "cat-list"),
+            "CatList"
+        );
+        assert_eq!(resolve_file_name(None, Some(&camel), "cat-list"), "catList");
+        assert_eq!(
+            resolve_file_name(None, Some(&snake), "cat-list"),
+            "cat_list"
+        );
+        assert_eq!(
+            resolve_file_name(None, Some(&pattern), "cat-list"),
+            "useCatList"
+        );
+
+        // A naming rule without a case inherits the policy's case style
+        let rule = NameRule {
+            suffix: Some(".test".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_file_name(Some(&rule), Some(&pascal), "cat-list"),
+            "CatList.test"
+        );
+    }
+
+    #[test]
+    fn test_apply_file_name_pattern() {
+        assert_eq!(
+            apply_file_name_pattern("{kebab}.{snake}.{camel}.{name}", "Cat List"),
+            "cat-list.cat_list.catList.Cat List"
+        );
+    }
+
     #[test]
     fn test_unicode_handling() {
         // Unicode in names should be preserved
@@ -439,6 +640,19 @@ mod test {
         assert_eq!(read_unicode_content, unicode_content);
     }
 
+    #[test]
+    fn test_list_subdirectories() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        create_folder(&temp_dir.path().join("users")).unwrap();
+        create_folder(&temp_dir.path().join("cats")).unwrap();
+        create_file(&temp_dir.path().join("index.ts"), String::new()).unwrap();
+
+        assert_eq!(list_subdirectories(temp_dir.path()), vec!["cats", "users"]);
+        assert!(list_subdirectories(&temp_dir.path().join("missing")).is_empty());
+    }
+
     #[test]
     fn test_create_file_in_nested_directory() {
         use tempfile::TempDir;
@@ -1,15 +1,75 @@
 use anyhow::{anyhow, Result};
 use handlebars::Handlebars;
-use std::{collections::BTreeMap, fs, path::PathBuf};
+use serde_json::{Map, Value};
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
 
-pub struct Generator {}
+/// Renders item templates. Partials from the shared templates directory are
+/// registered once, and each item template is compiled the first time it's rendered
+/// and reused for every later render by the same instance.
+pub struct Generator {
+    handlebars: RefCell<Handlebars<'static>>,
+    /// Item templates registered so far, by path; each is registered under its path
+    templates: RefCell<HashSet<PathBuf>>,
+}
 
 impl Generator {
-    pub fn generate(path: &PathBuf, name: String) -> Result<String> {
-        let source = match fs::read_to_string(&path) {
+    /// Create a generator, registering every `*.hbs` file under `templates_dir` as a partial.
+    /// Partial names are the path relative to the directory without extension
+    /// (`header.hbs` -> `{{> header}}`, `react/imports.hbs` -> `{{> react/imports}}`).
+    pub fn new(templates_dir: Option<&Path>) -> Result<Self> {
+        let mut handlebars = Handlebars::new();
+
+        if let Some(dir) = templates_dir {
+            if !dir.is_dir() {
+                return Err(anyhow!("Templates directory '{}' not found", dir.display()));
+            }
+            register_partials(&mut handlebars, dir, dir)?;
+        }
+
+        Ok(Self {
+            handlebars: RefCell::new(handlebars),
+            templates: RefCell::new(HashSet::new()),
+        })
+    }
+
+    /// Register every `*.hbs` file under `dir` as a partial, replacing shared partials
+    /// with the same name
+    pub fn register_overrides(&self, dir: &Path) -> Result<()> {
+        register_partials(&mut self.handlebars.borrow_mut(), dir, dir)
+    }
+
+    /// Names of the registered partials, sorted
+    pub fn partial_names(&self) -> Vec<String> {
+        let templates = self.templates.borrow();
+        let mut names: Vec<String> = self
+            .handlebars
+            .borrow()
+            .get_templates()
+            .keys()
+            .filter(|name| !templates.contains(Path::new(name.as_str())))
+            .cloned()
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Compile and register the template at `path` unless it already is, and return
+    /// the name it's registered under
+    pub fn load_template(&self, path: &Path) -> Result<String> {
+        let name = path.to_string_lossy().into_owned();
+        if self.templates.borrow().contains(path) {
+            return Ok(name);
+        }
+
+        let source = match fs::read_to_string(path) {
             Ok(s) => s,
             Err(err) => {
-                println!(
+                eprintln!(
                     "[warn] Failed to read the source directory path '{}': {}",
                     path.display(),
                     err
@@ -19,10 +79,9 @@ impl Generator {
             }
         };
 
-        let mut handlebars = Handlebars::new();
-
-        handlebars
-            .register_template_string("template", &source)
+        self.handlebars
+            .borrow_mut()
+            .register_template_string(&name, &source)
             .map_err(|err| {
                 anyhow!(
                     "Cannot register template string '{}' in Handlebars: {}",
@@ -30,18 +89,86 @@ impl Generator {
                     err
                 )
             })?;
+        self.templates.borrow_mut().insert(path.to_path_buf());
 
-        let mut data = BTreeMap::new();
-        data.insert("templateName".to_string(), name);
+        Ok(name)
+    }
 
-        let result = handlebars
-            .render("template", &data)
+    /// Render the template at `path` with the given template name and extra variables
+    pub fn render(
+        &self,
+        path: &Path,
+        name: String,
+        variables: &Map<String, Value>,
+    ) -> Result<String> {
+        let template = self.load_template(path)?;
+
+        let mut data = variables.clone();
+        data.insert("templateName".to_string(), Value::String(name));
+
+        let result = self
+            .handlebars
+            .borrow()
+            .render(&template, &data)
             .map_err(|err| anyhow!("Cannot render the template string: '{:?}' {}", data, err))?;
 
-        return Ok(result);
+        Ok(result)
+    }
+
+    /// Render a single template without shared partials
+    pub fn generate(path: &Path, name: String) -> Result<String> {
+        Generator::new(None)?.render(path, name, &Map::new())
     }
 }
 
+/// Recursively register `*.hbs` files under `dir` as partials named relative to `root`
+fn register_partials(handlebars: &mut Handlebars<'static>, root: &Path, dir: &Path) -> Result<()> {
+    let entries = fs::read_dir(dir).map_err(|err| {
+        anyhow!(
+            "Failed to read templates directory '{}': {}",
+            dir.display(),
+            err
+        )
+    })?;
+
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    paths.sort();
+
+    for path in paths {
+        if path.is_dir() {
+            register_partials(handlebars, root, &path)?;
+            continue;
+        }
+
+        if path.extension().and_then(|ext| ext.to_str()) != Some("hbs") {
+            continue;
+        }
+
+        let relative = path.strip_prefix(root).unwrap_or(&path).with_extension("");
+        let partial_name = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let source = fs::read_to_string(&path)
+            .map_err(|err| anyhow!("Failed to read partial '{}': {}", path.display(), err))?;
+
+        handlebars
+            .register_partial(&partial_name, source)
+            .map_err(|err| {
+                anyhow!(
+                    "Cannot register partial '{}' from '{}': {}",
+                    partial_name,
+                    path.display(),
+                    err
+                )
+            })?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,6 +319,148 @@ export default {{templateName}};"#;
         assert!(result.contains("// UserDashboard logic here"));
     }
 
+    #[test]
+    fn test_render_with_partials_from_templates_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let templates_dir = temp_dir.path().join("templates");
+        fs::create_dir_all(templates_dir.join("react")).unwrap();
+
+        fs::write(
+            templates_dir.join("header.hbs"),
+            "// Generated for {{templateName}}",
+        )
+        .unwrap();
+        fs::write(
+            templates_dir.join("react/imports.hbs"),
+            "import React from 'react';",
+        )
+        .unwrap();
+        fs::write(templates_dir.join("README.md"), "not a partial").unwrap();
+
+        let template_path = templates_dir.join("component.hbs");
+        fs::write(
+            &template_path,
+            "{{> header}}\n{{> react/imports}}\n\nexport function {{templateName}}() {}",
+        )
+        .unwrap();
+
+        let generator = Generator::new(Some(&templates_dir)).unwrap();
+        assert_eq!(
+            generator.partial_names(),
+            vec!["component", "header", "react/imports"]
+        );
+
+        // The same generator is reused for several renders
+        for name in ["CatList", "UserProfile"] {
+            let result = generator
+                .render(&template_path, name.to_string(), &Map::new())
+                .unwrap();
+            assert!(result.starts_with(&format!("// Generated for {}", name)));
+            assert!(result.contains("import React from 'react';"));
+            assert!(result.contains(&format!("export function {}() {{}}", name)));
+        }
+    }
+
+    #[test]
+    fn test_override_partials_replace_shared_ones() {
+        let temp_dir = TempDir::new().unwrap();
+        let shared_dir = temp_dir.path().join("shared");
+        let overrides_dir = temp_dir.path().join("overrides");
+        fs::create_dir_all(&shared_dir).unwrap();
+        fs::create_dir_all(&overrides_dir).unwrap();
+        fs::write(shared_dir.join("header.hbs"), "// shared").unwrap();
+        fs::write(shared_dir.join("footer.hbs"), "// end").unwrap();
+        fs::write(overrides_dir.join("header.hbs"), "// mine").unwrap();
+        let template_path = temp_dir.path().join("component.hbs");
+        fs::write(&template_path, "{{> header}} {{> footer}}").unwrap();
+
+        let generator = Generator::new(Some(&shared_dir)).unwrap();
+        generator.register_overrides(&overrides_dir).unwrap();
+
+        assert_eq!(generator.partial_names(), vec!["footer", "header"]);
+        let result = generator
+            .render(&template_path, "A".to_string(), &Map::new())
+            .unwrap();
+        assert_eq!(result, "// mine // end");
+    }
+
+    #[test]
+    fn test_render_reuses_loaded_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let template_path = temp_dir.path().join("components.hbs");
+        fs::write(&template_path, "v1 {{templateName}}").unwrap();
+
+        let generator = Generator::new(None).unwrap();
+        let first = generator
+            .render(&template_path, "A".to_string(), &Map::new())
+            .unwrap();
+        assert_eq!(first, "v1 A");
+
+        // Later renders use the registered template instead of reading the file again
+        fs::write(&template_path, "v2 {{templateName}}").unwrap();
+        let second = generator
+            .render(&template_path, "B".to_string(), &Map::new())
+            .unwrap();
+        assert_eq!(second, "v1 B");
+        assert!(generator.partial_names().is_empty());
+    }
+
+    #[test]
+    fn test_new_with_missing_templates_dir() {
+        let result = Generator::new(Some(Path::new("/nonexistent/templates")));
+        assert!(result.is_err());
+        assert!(result
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("Templates directory"));
+    }
+
+    #[test]
+    fn test_render_with_missing_partial() {
+        let temp_dir = TempDir::new().unwrap();
+        let template_path = temp_dir.path().join("template.hbs");
+        fs::write(&template_path, "{{> missing}}").unwrap();
+
+        let generator = Generator::new(None).unwrap();
+        let result = generator.render(&template_path, "Test".to_string(), &Map::new());
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Cannot render the template string"));
+    }
+
+    #[test]
+    fn test_render_with_variables() {
+        let temp_dir = TempDir::new().unwrap();
+        let template_path = temp_dir.path().join("component.hbs");
+        fs::write(
+            &template_path,
+            "{{#if withProps}}interface {{templateName}}Props {}\n{{/if}}export function {{templateName}}() {} // {{stateLib}}",
+        )
+        .unwrap();
+
+        let generator = Generator::new(None).unwrap();
+
+        let mut variables = Map::new();
+        variables.insert("withProps".to_string(), Value::Bool(true));
+        variables.insert("stateLib".to_string(), Value::String("redux".to_string()));
+        let result = generator
+            .render(&template_path, "CatList".to_string(), &variables)
+            .unwrap();
+        assert_eq!(
+            result,
+            "interface CatListProps {}\nexport function CatList() {} // redux"
+        );
+
+        variables.insert("withProps".to_string(), Value::Bool(false));
+        let result = generator
+            .render(&template_path, "CatList".to_string(), &variables)
+            .unwrap();
+        assert_eq!(result, "export function CatList() {} // redux");
+    }
+
     #[test]
     fn test_generate_handlebars_escaping() {
         let temp_dir = TempDir::new().unwrap();
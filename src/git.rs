@@ -0,0 +1,194 @@
+use anyhow::{anyhow, Result};
+use std::ffi::OsStr;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Commit message used when `git.commit_message` isn't set
+pub const DEFAULT_COMMIT_MESSAGE: &str = "chore({module}): scaffold {name} {item_type}";
+
+/// Fail unless `dir` is inside a git work tree
+pub fn ensure_repository(dir: &Path) -> Result<()> {
+    match git(dir, ["rev-parse", "--is-inside-work-tree"]) {
+        Ok(output) if output.trim() == "true" => Ok(()),
+        Ok(_) | Err(GitError::Failed(_)) => Err(anyhow!(
+            "'{}' is not inside a git repository.\n💡 Run `git init`, or turn off the git options in your config",
+            dir.display()
+        )),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// The files with uncommitted changes: modified, staged, deleted or untracked.
+/// Files that don't exist and aren't tracked are clean.
+pub fn dirty_files(dir: &Path, files: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut dirty = Vec::new();
+    for file in files {
+        let status = git(
+            dir,
+            [
+                OsStr::new("status"),
+                OsStr::new("--porcelain"),
+                OsStr::new("--untracked-files=all"),
+                OsStr::new("--"),
+                file.as_os_str(),
+            ],
+        )?;
+        if !status.trim().is_empty() {
+            dirty.push(file.clone());
+        }
+    }
+    Ok(dirty)
+}
+
+/// `git add` the files
+pub fn stage(dir: &Path, files: &[PathBuf]) -> Result<()> {
+    let args = [OsStr::new("add"), OsStr::new("--")]
+        .into_iter()
+        .chain(files.iter().map(|file| file.as_os_str()));
+    git(dir, args)?;
+    Ok(())
+}
+
+/// Commit only the given files, leaving anything else that is staged out of the commit.
+/// The files must be staged or tracked.
+pub fn commit(dir: &Path, files: &[PathBuf], message: &str) -> Result<()> {
+    let args = [
+        OsStr::new("commit"),
+        OsStr::new("--quiet"),
+        OsStr::new("--message"),
+        OsStr::new(message),
+        OsStr::new("--"),
+    ]
+    .into_iter()
+    .chain(files.iter().map(|file| file.as_os_str()));
+    git(dir, args)?;
+    Ok(())
+}
+
+/// Why a git command didn't succeed
+#[derive(Debug)]
+enum GitError {
+    /// git isn't installed or couldn't be started
+    Unavailable(String),
+    /// git ran and failed, with its error output
+    Failed(String),
+}
+
+impl From<GitError> for anyhow::Error {
+    fn from(error: GitError) -> Self {
+        match error {
+            GitError::Unavailable(reason) => anyhow!(
+                "Failed to run git: {}\n💡 Install git, or turn off the git options in your config",
+                reason
+            ),
+            GitError::Failed(message) => anyhow!("git failed: {}", message),
+        }
+    }
+}
+
+/// Run git in `dir` and return its standard output
+fn git<I, S>(dir: &Path, args: I) -> std::result::Result<String, GitError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => GitError::Unavailable("git was not found".to_string()),
+            _ => GitError::Unavailable(e.to_string()),
+        })?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let message = if stderr.trim().is_empty() {
+            stdout.trim()
+        } else {
+            stderr.trim()
+        };
+        Err(GitError::Failed(message.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// A repository with one committed file, `tracked.ts`
+    fn test_repository() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        for args in [
+            vec!["init", "--quiet"],
+            vec!["config", "user.name", "Test"],
+            vec!["config", "user.email", "test@example.com"],
+            vec!["config", "commit.gpgsign", "false"],
+        ] {
+            git(dir, args).unwrap();
+        }
+        fs::write(dir.join("tracked.ts"), "a").unwrap();
+        git(dir, ["add", "tracked.ts"]).unwrap();
+        git(dir, ["commit", "--quiet", "-m", "initial"]).unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_ensure_repository() {
+        let repository = test_repository();
+        assert!(ensure_repository(repository.path()).is_ok());
+
+        let outside = TempDir::new().unwrap();
+        let error_msg = ensure_repository(outside.path()).unwrap_err().to_string();
+        assert!(error_msg.contains("is not inside a git repository"));
+    }
+
+    #[test]
+    fn test_dirty_files() {
+        let repository = test_repository();
+        let dir = repository.path();
+        let files = vec![
+            PathBuf::from("tracked.ts"),
+            PathBuf::from("untracked.ts"),
+            PathBuf::from("missing.ts"),
+        ];
+        assert!(dirty_files(dir, &files).unwrap().is_empty());
+
+        fs::write(dir.join("tracked.ts"), "b").unwrap();
+        fs::write(dir.join("untracked.ts"), "c").unwrap();
+        assert_eq!(
+            dirty_files(dir, &files).unwrap(),
+            vec![PathBuf::from("tracked.ts"), PathBuf::from("untracked.ts")]
+        );
+    }
+
+    #[test]
+    fn test_stage_and_commit_only_given_files() {
+        let repository = test_repository();
+        let dir = repository.path();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/cat-list.tsx"), "x").unwrap();
+        fs::write(dir.join("other.ts"), "y").unwrap();
+        git(dir, ["add", "other.ts"]).unwrap();
+
+        let files = vec![PathBuf::from("src/cat-list.tsx")];
+        stage(dir, &files).unwrap();
+        commit(dir, &files, "chore(cats): scaffold cat-list components").unwrap();
+
+        let log = git(dir, ["log", "-1", "--name-only", "--format=%s"]).unwrap();
+        assert_eq!(
+            log.trim(),
+            "chore(cats): scaffold cat-list components\n\nsrc/cat-list.tsx"
+        );
+        // Changes staged before the create stay staged and uncommitted
+        let status = git(dir, ["status", "--porcelain"]).unwrap();
+        assert_eq!(status.trim(), "A  other.ts");
+    }
+}
@@ -0,0 +1,222 @@
+/// ANSI colors used for each kind of token
+const KEYWORD: &str = "\x1b[35m";
+const STRING: &str = "\x1b[32m";
+const NUMBER: &str = "\x1b[33m";
+const COMMENT: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+const JS_KEYWORDS: &[&str] = &[
+    "as",
+    "async",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "private",
+    "public",
+    "readonly",
+    "return",
+    "static",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "undefined",
+    "var",
+    "while",
+];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "const", "crate", "else", "enum", "false", "fn", "for", "if", "impl",
+    "in", "let", "loop", "match", "mod", "mut", "pub", "ref", "return", "self", "Self", "static",
+    "struct", "super", "trait", "true", "type", "use", "where", "while",
+];
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "and", "as", "async", "await", "class", "def", "elif", "else", "except", "False", "for",
+    "from", "if", "import", "in", "is", "lambda", "None", "not", "or", "pass", "raise", "return",
+    "True", "try", "while", "with", "yield",
+];
+
+/// How to recognize tokens in one language
+struct Syntax {
+    keywords: &'static [&'static str],
+    line_comment: Option<&'static str>,
+    block_comments: bool,
+}
+
+/// Pick the syntax for a file extension such as `tsx` or `test.ts`. Unknown
+/// extensions still get strings and numbers highlighted.
+fn syntax_for(extension: &str) -> Syntax {
+    let extension = extension.rsplit('.').next().unwrap_or(extension);
+    match extension {
+        "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" => Syntax {
+            keywords: JS_KEYWORDS,
+            line_comment: Some("//"),
+            block_comments: true,
+        },
+        "rs" => Syntax {
+            keywords: RUST_KEYWORDS,
+            line_comment: Some("//"),
+            block_comments: true,
+        },
+        "py" => Syntax {
+            keywords: PYTHON_KEYWORDS,
+            line_comment: Some("#"),
+            block_comments: false,
+        },
+        "css" | "scss" | "less" | "java" | "kt" | "swift" | "go" | "c" | "h" | "cpp" | "cs" => {
+            Syntax {
+                keywords: &[],
+                line_comment: Some("//"),
+                block_comments: true,
+            }
+        }
+        "sh" | "yml" | "yaml" | "toml" => Syntax {
+            keywords: &[],
+            line_comment: Some("#"),
+            block_comments: false,
+        },
+        _ => Syntax {
+            keywords: &[],
+            line_comment: None,
+            block_comments: false,
+        },
+    }
+}
+
+/// Color keywords, strings, numbers and comments of `source` with ANSI escapes,
+/// based on the output file's extension
+pub fn highlight(source: &str, extension: &str) -> String {
+    let syntax = syntax_for(extension);
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::with_capacity(source.len());
+    let mut i = 0;
+
+    let starts_with = |i: usize, prefix: &str| {
+        prefix
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| chars.get(i + offset) == Some(&c))
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        let color = if syntax
+            .line_comment
+            .is_some_and(|prefix| starts_with(i, prefix))
+        {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            COMMENT
+        } else if syntax.block_comments && starts_with(i, "/*") {
+            i += 2;
+            while i < chars.len() && !starts_with(i, "*/") {
+                i += 1;
+            }
+            i = (i + 2).min(chars.len());
+            COMMENT
+        } else if matches!(c, '"' | '\'' | '`') {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            STRING
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                i += 1;
+            }
+            NUMBER
+        } else if c.is_alphabetic() || c == '_' || c == '$' {
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$')
+            {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            if syntax.keywords.contains(&word.as_str()) {
+                KEYWORD
+            } else {
+                out.push_str(&word);
+                continue;
+            }
+        } else {
+            out.push(c);
+            i += 1;
+            continue;
+        };
+
+        out.push_str(color);
+        out.extend(&chars[start..i]);
+        out.push_str(RESET);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strip_ansi(text: &str) -> String {
+        regex::Regex::new("\x1b\\[[0-9;]*m")
+            .unwrap()
+            .replace_all(text, "")
+            .into_owned()
+    }
+
+    #[test]
+    fn test_highlight_typescript() {
+        let source = "export const count = 42; // answer\nconst name = 'it\\'s';\n/* block */";
+        let highlighted = highlight(source, "tsx");
+
+        assert_eq!(strip_ansi(&highlighted), source);
+        assert!(highlighted.starts_with("\x1b[35mexport\x1b[0m \x1b[35mconst\x1b[0m count"));
+        assert!(highlighted.contains("\x1b[33m42\x1b[0m"));
+        assert!(highlighted.contains("\x1b[90m// answer\x1b[0m"));
+        assert!(highlighted.contains("\x1b[32m'it\\'s'\x1b[0m"));
+        assert!(highlighted.contains("\x1b[90m/* block */\x1b[0m"));
+    }
+
+    #[test]
+    fn test_highlight_by_extension() {
+        // Python comments start with '#', and the last part of `test.py` picks the syntax
+        let highlighted = highlight("def run(): # todo", "test.py");
+        assert!(highlighted.contains("\x1b[35mdef\x1b[0m"));
+        assert!(highlighted.contains("\x1b[90m# todo\x1b[0m"));
+
+        // Unknown extensions only highlight strings and numbers
+        let highlighted = highlight("const x = \"a\" // 1", "txt");
+        assert!(highlighted.starts_with("const x = \x1b[32m\"a\"\x1b[0m // \x1b[33m1"));
+    }
+}
@@ -0,0 +1,400 @@
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::config::{HookFailure, Hooks};
+
+/// Placeholders hook commands may use, replaced before the command runs
+pub const PLACEHOLDERS: &[&str] = &["files", "name", "item_type", "module"];
+
+/// How long a hook command may run when its hooks don't set a timeout
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How often a running hook is checked for completion
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// When a hook runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    PreGenerate,
+    PostGenerate,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::PreGenerate => write!(f, "pre_generate"),
+            Stage::PostGenerate => write!(f, "post_generate"),
+        }
+    }
+}
+
+/// A hook command with the settings of the hooks that declare it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookStep {
+    pub command: String,
+    pub timeout: Duration,
+    pub on_failure: HookFailure,
+}
+
+/// Values substituted for the placeholders of a hook command
+#[derive(Debug, Clone)]
+pub struct HookContext<'a> {
+    /// Files about to be written (pre_generate) or created (post_generate)
+    pub files: &'a [PathBuf],
+    pub name: &'a str,
+    pub item_type: &'a str,
+    pub module: &'a str,
+}
+
+/// The commands to run for a stage: the project's first, then the item's. Item hooks
+/// without their own timeout or failure policy use the project's.
+pub fn hook_steps(project: Option<&Hooks>, item: Option<&Hooks>, stage: Stage) -> Vec<HookStep> {
+    let project_timeout = project.and_then(|hooks| hooks.timeout);
+    let project_on_failure = project.and_then(|hooks| hooks.on_failure);
+
+    [project, item]
+        .into_iter()
+        .flatten()
+        .flat_map(|hooks| {
+            let commands = match stage {
+                Stage::PreGenerate => &hooks.pre_generate,
+                Stage::PostGenerate => &hooks.post_generate,
+            };
+            let timeout = hooks
+                .timeout
+                .or(project_timeout)
+                .map_or(DEFAULT_TIMEOUT, Duration::from_secs);
+            let on_failure = hooks.on_failure.or(project_on_failure).unwrap_or_default();
+            commands.iter().map(move |command| HookStep {
+                command: command.clone(),
+                timeout,
+                on_failure,
+            })
+        })
+        .collect()
+}
+
+/// The first `{placeholder}` in a command that isn't one of `PLACEHOLDERS`. Shell
+/// parameter expansions such as `${HOME}` are left alone.
+pub fn unknown_placeholder(command: &str) -> Option<String> {
+    placeholder_regex()
+        .captures_iter(command)
+        .filter(|captures| captures.get(1).is_none())
+        .map(|captures| captures[2].to_string())
+        .find(|name| !PLACEHOLDERS.contains(&name.as_str()))
+}
+
+/// Replace the placeholders of a command. `{files}` becomes the files as separate,
+/// quoted shell arguments.
+pub fn expand_command(command: &str, context: &HookContext) -> String {
+    fill_placeholders(command, context, shell_quote)
+}
+
+/// Replace the placeholders of `text` with the context's values, each passed through
+/// `format`. `{files}` joins the files with spaces.
+pub fn fill_placeholders(
+    text: &str,
+    context: &HookContext,
+    format: impl Fn(&str) -> String,
+) -> String {
+    placeholder_regex()
+        .replace_all(text, |captures: &regex::Captures| {
+            if captures.get(1).is_some() {
+                return captures[0].to_string();
+            }
+            match &captures[2] {
+                "files" => context
+                    .files
+                    .iter()
+                    .map(|file| format(&file.to_string_lossy()))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                "name" => format(context.name),
+                "item_type" => format(context.item_type),
+                "module" => format(context.module),
+                _ => captures[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
+/// Run the hooks of a stage in order from `dir`. A failing pre_generate hook always
+/// fails, cancelling the create. A failing post_generate hook fails when its policy
+/// is abort, and otherwise prints a warning. Output goes to stderr so `--output json`
+/// stays parseable.
+pub fn run_hooks(
+    steps: &[HookStep],
+    stage: Stage,
+    context: &HookContext,
+    dir: &Path,
+    announce: bool,
+) -> Result<()> {
+    for step in steps {
+        let command = expand_command(&step.command, context);
+        if announce {
+            println!("🪝 Running {} hook: {}", stage, command);
+        }
+
+        if let Err(e) = run_command(&command, dir, step.timeout) {
+            let error = anyhow!("{} hook '{}' {}", stage, step.command, e);
+            match (stage, step.on_failure) {
+                (Stage::PreGenerate, _) | (Stage::PostGenerate, HookFailure::Abort) => {
+                    return Err(error)
+                }
+                (Stage::PostGenerate, HookFailure::Warn) => eprintln!("⚠️  {}", error),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run a command with the platform shell, stopping it after `timeout`
+fn run_command(command: &str, dir: &Path, timeout: Duration) -> Result<()> {
+    let mut child = shell(command)
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(std::io::stderr()))
+        .spawn()
+        .map_err(|e| anyhow!("could not be started: {}", e))?;
+
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child
+            .try_wait()
+            .map_err(|e| anyhow!("could not be waited for: {}", e))?
+        {
+            return if status.success() {
+                Ok(())
+            } else {
+                Err(anyhow!("exited with {}", status))
+            };
+        }
+
+        if started.elapsed() >= timeout {
+            // The process may finish between the check and the kill
+            kill_tree(child.id());
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(anyhow!(
+                "timed out after {} second(s)",
+                timeout.as_secs_f32()
+            ));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// The shell runs in its own process group, so a timeout can stop everything the
+/// command started
+#[cfg(not(windows))]
+fn shell(command: &str) -> Command {
+    use std::os::unix::process::CommandExt;
+
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command).process_group(0);
+    shell
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
+
+/// Kill a hook's process group, including the processes its shell started
+#[cfg(not(windows))]
+fn kill_tree(pid: u32) {
+    let _ = Command::new("kill")
+        .args(["-KILL", "--", &format!("-{}", pid)])
+        .stderr(Stdio::null())
+        .status();
+}
+
+/// Kill a hook's process and the processes it started
+#[cfg(windows)]
+fn kill_tree(pid: u32) {
+    let _ = Command::new("taskkill")
+        .args(["/T", "/F", "/PID", &pid.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+}
+
+/// Quote an argument for the platform shell
+#[cfg(not(windows))]
+fn shell_quote(arg: &str) -> String {
+    if !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./".contains(c))
+    {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+/// Quote an argument for the platform shell
+#[cfg(windows)]
+fn shell_quote(arg: &str) -> String {
+    format!("\"{}\"", arg.replace('"', "\"\""))
+}
+
+/// `{name}` placeholders; the first group is set for shell expansions like `${name}`
+fn placeholder_regex() -> &'static Regex {
+    static PLACEHOLDER: OnceLock<Regex> = OnceLock::new();
+    PLACEHOLDER.get_or_init(|| Regex::new(r"(\$)?\{([A-Za-z_][A-Za-z0-9_]*)\}").unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn context(files: &[PathBuf]) -> HookContext<'_> {
+        HookContext {
+            files,
+            name: "cat-list",
+            item_type: "components",
+            module: "cats",
+        }
+    }
+
+    #[test]
+    fn test_expand_command() {
+        let files = vec![
+            PathBuf::from("src/modules/cats/components/cat-list.tsx"),
+            PathBuf::from("src/my dir/it's.ts"),
+        ];
+        assert_eq!(
+            expand_command("prettier --write {files}", &context(&files)),
+            r"prettier --write src/modules/cats/components/cat-list.tsx 'src/my dir/it'\''s.ts'"
+        );
+        assert_eq!(
+            expand_command("echo {module}/{item_type}/{name} ${HOME}", &context(&[])),
+            "echo cats/components/cat-list ${HOME}"
+        );
+
+        assert_eq!(unknown_placeholder("prettier --write {files}"), None);
+        assert_eq!(unknown_placeholder("echo ${HOME} {a,b}"), None);
+        assert_eq!(
+            unknown_placeholder("eslint --fix {file}"),
+            Some("file".to_string())
+        );
+    }
+
+    #[test]
+    fn test_hook_steps_inherit_project_settings() {
+        let project = Hooks {
+            pre_generate: vec!["check".to_string()],
+            post_generate: vec!["prettier --write {files}".to_string()],
+            timeout: Some(5),
+            on_failure: Some(HookFailure::Abort),
+        };
+        let item = Hooks {
+            post_generate: vec!["eslint --fix {files}".to_string()],
+            on_failure: Some(HookFailure::Warn),
+            ..Default::default()
+        };
+
+        let steps = hook_steps(Some(&project), Some(&item), Stage::PostGenerate);
+        assert_eq!(
+            steps,
+            vec![
+                HookStep {
+                    command: "prettier --write {files}".to_string(),
+                    timeout: Duration::from_secs(5),
+                    on_failure: HookFailure::Abort,
+                },
+                HookStep {
+                    command: "eslint --fix {files}".to_string(),
+                    timeout: Duration::from_secs(5),
+                    on_failure: HookFailure::Warn,
+                },
+            ]
+        );
+
+        let steps = hook_steps(None, Some(&item), Stage::PreGenerate);
+        assert!(steps.is_empty());
+        let steps = hook_steps(None, Some(&project), Stage::PreGenerate);
+        assert_eq!(steps[0].timeout, Duration::from_secs(5));
+        assert_eq!(
+            hook_steps(Some(&item), None, Stage::PostGenerate)[0].timeout,
+            DEFAULT_TIMEOUT
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_hooks_failure_policy() {
+        let temp_dir = TempDir::new().unwrap();
+        let step = |command: &str, on_failure| HookStep {
+            command: command.to_string(),
+            timeout: Duration::from_secs(5),
+            on_failure,
+        };
+        let files = vec![PathBuf::from("a.ts"), PathBuf::from("b.ts")];
+        let run = |steps: &[HookStep], stage| {
+            run_hooks(steps, stage, &context(&files), temp_dir.path(), false)
+        };
+
+        // Commands run in the given directory with the placeholders filled in
+        run(
+            &[step("echo {files} > out.txt", HookFailure::Warn)],
+            Stage::PostGenerate,
+        )
+        .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(temp_dir.path().join("out.txt")).unwrap(),
+            "a.ts b.ts\n"
+        );
+
+        // Failing post_generate hooks warn unless their policy is abort
+        assert!(run(&[step("exit 3", HookFailure::Warn)], Stage::PostGenerate).is_ok());
+        let error_msg = run(&[step("exit 3", HookFailure::Abort)], Stage::PostGenerate)
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("post_generate hook 'exit 3' exited with"));
+
+        // Failing pre_generate hooks always cancel
+        let error_msg = run(&[step("false", HookFailure::Warn)], Stage::PreGenerate)
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.starts_with("pre_generate hook 'false'"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_command_timeout() {
+        let temp_dir = TempDir::new().unwrap();
+        let started = Instant::now();
+        let error_msg = run_command("sleep 5", temp_dir.path(), Duration::from_millis(200))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("timed out"));
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_command_timeout_stops_child_processes() {
+        let temp_dir = TempDir::new().unwrap();
+        let marker = temp_dir.path().join("marker");
+        let result = run_command(
+            "(sleep 1; touch marker) & sleep 5",
+            temp_dir.path(),
+            Duration::from_millis(200),
+        );
+        assert!(result.is_err());
+
+        thread::sleep(Duration::from_millis(1500));
+        assert!(!marker.exists());
+    }
+}
@@ -1,6 +1,23 @@
 pub mod app;
 pub mod cli_engine;
+pub mod completion;
 pub mod config;
+pub mod diagnostics;
+pub mod doctor;
+pub mod extends;
 pub mod file_utils;
 pub mod generator;
+pub mod git;
+pub mod highlight;
+pub mod hooks;
+pub mod migrate;
 pub mod opts;
+pub mod path_resolver;
+pub mod snapshots;
+pub mod suggest;
+pub mod user_config;
+pub mod validation;
+pub mod variables;
+pub mod watch;
+pub mod wizard;
+pub mod workspace;
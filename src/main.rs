@@ -1,13 +1,47 @@
 use clap::Parser;
+use std::path::PathBuf;
 
-use creator::app::{execute_config, Config};
-use creator::opts::Opts;
+use creator::app::{execute_config, execute_init, load_quietly, Config};
+use creator::completion::{complete_create_path, completion_script};
+use creator::opts::{Commands, Opts};
 
 fn main() -> anyhow::Result<()> {
-    println!("🚀 Creator v1.0 - Dynamic Configuration System");
-
     let opts = Opts::parse();
 
+    // Completion output is read by the shell, so it must not include the banner
+    match &opts.commands {
+        Some(Commands::Completions { shell }) => {
+            print!("{}", completion_script(*shell));
+            return Ok(());
+        }
+        Some(Commands::Complete { current }) => {
+            if let Some((project_config, source_dir)) = load_quietly(&opts) {
+                for candidate in complete_create_path(&project_config, &source_dir, current) {
+                    println!("{}", candidate);
+                }
+            }
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    // Status lines go to stderr so stdout only carries command output, such as
+    // `--output json` summaries
+    eprintln!("🚀 Creator v1.0 - Dynamic Configuration System");
+
+    // Init creates the config, so it runs before looking for one
+    if let Some(Commands::Init { preset, wizard }) = &opts.commands {
+        let config_path = opts
+            .config
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("config.json"));
+        if let Err(e) = execute_init(preset.as_deref(), *wizard, &config_path) {
+            eprintln!("❌ Execution error: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Try to load configuration with graceful error handling
     let config = match Config::try_from(opts) {
         Ok(config) => config,
@@ -17,7 +51,6 @@ fn main() -> anyhow::Result<()> {
             // Check if it's the "no command specified" error (CLI-first behavior)
             if error_msg.contains("No command specified") {
                 // Show user-friendly help for CLI-first design
-                eprintln!("🚀 Creator v1.0 - Dynamic Configuration System");
                 eprintln!();
                 eprintln!("Creator requires explicit commands for automation-friendly operation.");
                 eprintln!();
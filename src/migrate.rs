@@ -0,0 +1,222 @@
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::Path;
+
+/// Schema version of the config format understood and written by this release
+pub const CURRENT_SCHEMA_VERSION: u64 = 2;
+
+/// Configs written before `schema_version` existed
+const UNVERSIONED_SCHEMA_VERSION: u64 = 1;
+
+/// Upgrade from one schema version to the next
+struct Migration {
+    from: u64,
+    /// Rewrite the raw config in place, recording a note for every change
+    apply: fn(&mut Value, &mut Vec<String>),
+}
+
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 1,
+    apply: strip_extension_dots,
+}];
+
+/// Read the schema version a raw config is written in
+pub fn schema_version(value: &Value) -> Result<u64> {
+    let version = match value.get("schema_version") {
+        None | Some(Value::Null) => return Ok(UNVERSIONED_SCHEMA_VERSION),
+        Some(version) => version
+            .as_u64()
+            .filter(|version| *version > 0)
+            .ok_or_else(|| {
+                anyhow!(
+                    "'schema_version' must be a positive integer, found {}",
+                    version
+                )
+            })?,
+    };
+
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(anyhow!(
+            "Config schema_version {} is newer than this version of Creator supports ({}).\n💡 Upgrade creator to use this config",
+            version,
+            CURRENT_SCHEMA_VERSION
+        ));
+    }
+
+    Ok(version)
+}
+
+/// Upgrade a raw config to the current schema version.
+/// Returns a note for every change; an empty list means the config was already current.
+pub fn migrate(value: &mut Value) -> Result<Vec<String>> {
+    let version = schema_version(value)?;
+    let mut changes = Vec::new();
+
+    for migration in MIGRATIONS.iter().filter(|m| m.from >= version) {
+        (migration.apply)(value, &mut changes);
+    }
+
+    if version < CURRENT_SCHEMA_VERSION {
+        if let Value::Object(map) = value {
+            // Keep `schema_version` as the first key so it's easy to spot
+            let mut stamped = Map::new();
+            stamped.insert("schema_version".to_string(), CURRENT_SCHEMA_VERSION.into());
+            stamped.extend(
+                std::mem::take(map)
+                    .into_iter()
+                    .filter(|(key, _)| key != "schema_version"),
+            );
+            *map = stamped;
+        }
+        changes.push(format!(
+            "schema_version: {} -> {}",
+            version, CURRENT_SCHEMA_VERSION
+        ));
+    }
+
+    Ok(changes)
+}
+
+/// Upgrade a config file to the current schema version, rewriting it unless `dry_run`.
+/// Only the file itself is migrated, not the configs it extends.
+pub fn migrate_file(path: &Path, dry_run: bool) -> Result<Vec<String>> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read config file {}: {}", path.display(), e))?;
+    let mut value: Value = serde_json::from_str(&contents)
+        .map_err(|e| anyhow!("Failed to parse config JSON in {}: {}", path.display(), e))?;
+
+    let changes = migrate(&mut value)?;
+    if !changes.is_empty() && !dry_run {
+        let json = serde_json::to_string_pretty(&value)
+            .map_err(|e| anyhow!("Failed to serialize the migrated config: {}", e))?;
+        fs::write(path, json + "\n")
+            .map_err(|e| anyhow!("Failed to write config file {}: {}", path.display(), e))?;
+    }
+
+    Ok(changes)
+}
+
+/// 1 -> 2: file extensions are written without a leading dot (`tsx`, not `.tsx`).
+/// Version 1 joined them as-is, so `.tsx` produced `name..tsx`.
+fn strip_extension_dots(value: &mut Value, changes: &mut Vec<String>) {
+    fn walk(value: &mut Value, path: &str, changes: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map.iter_mut() {
+                    let child_path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+
+                    match child {
+                        Value::String(extension)
+                            if key == "file_extension" && extension.starts_with('.') =>
+                        {
+                            let stripped = extension.trim_start_matches('.').to_string();
+                            changes
+                                .push(format!("{}: '{}' -> '{}'", child_path, extension, stripped));
+                            *extension = stripped;
+                        }
+                        _ => walk(child, &child_path, changes),
+                    }
+                }
+            }
+            Value::Array(values) => {
+                for (index, child) in values.iter_mut().enumerate() {
+                    walk(child, &format!("{}[{}]", path, index), changes);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    walk(value, "", changes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_migrate_unversioned_config() {
+        let mut value = json!({
+            "project": {
+                "name": "app",
+                "version": "1.0",
+                "structure": {
+                    "modules": {
+                        "allow_dynamic_children": true,
+                        "default_structure": {
+                            "components": {
+                                "template": "templates/components.hbs",
+                                "file_extension": ".tsx",
+                                "files": [
+                                    { "template": "templates/test.hbs", "file_extension": ".test.tsx" }
+                                ]
+                            },
+                            "hooks": { "template": "templates/hooks.hbs", "file_extension": "ts" }
+                        }
+                    }
+                }
+            }
+        });
+
+        let changes = migrate(&mut value).unwrap();
+        assert_eq!(
+            changes,
+            vec![
+                "project.structure.modules.default_structure.components.file_extension: '.tsx' -> 'tsx'",
+                "project.structure.modules.default_structure.components.files[0].file_extension: '.test.tsx' -> 'test.tsx'",
+                "schema_version: 1 -> 2",
+            ]
+        );
+        assert_eq!(
+            value.as_object().unwrap().keys().next().map(String::as_str),
+            Some("schema_version")
+        );
+        assert_eq!(value["schema_version"], 2);
+
+        // Migrating again is a no-op
+        assert!(migrate(&mut value).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_migrate_file_keeps_key_order() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.json");
+        let original = r#"{ "project": { "name": "app", "version": "1.0", "structure": {
+            "pages": { "children": { "home": { "template": "t.hbs", "file_extension": ".tsx" } } },
+            "modules": { "allow_dynamic_children": true, "default_structure": {
+                "hooks": { "template": "t.hbs", "file_extension": "ts" } } }
+        } } }"#;
+        fs::write(&path, original).unwrap();
+
+        let changes = migrate_file(&path, true).unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+
+        migrate_file(&path, false).unwrap();
+        let migrated = fs::read_to_string(&path).unwrap();
+        assert!(migrated.starts_with("{\n  \"schema_version\": 2,\n  \"project\""));
+        assert!(migrated.find("\"pages\"").unwrap() < migrated.find("\"modules\"").unwrap());
+        assert!(migrated.contains("\"file_extension\": \"tsx\""));
+    }
+
+    #[test]
+    fn test_schema_version_errors() {
+        let error_msg = schema_version(&json!({ "schema_version": 99 }))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("newer than this version of Creator supports (2)"));
+
+        let error_msg = schema_version(&json!({ "schema_version": "2" }))
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("must be a positive integer"));
+
+        assert_eq!(schema_version(&json!({})).unwrap(), 1);
+    }
+}
@@ -1,6 +1,9 @@
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
+use crate::completion::CompletionShell;
+use crate::config::{ConflictPolicy, OutputFormat};
+
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 #[clap(
@@ -14,16 +17,132 @@ pub struct Opts {
     #[clap(short = 's', long = "source_dir")]
     pub source_dir: Option<PathBuf>,
 
+    #[clap(
+        long = "project",
+        help = "Workspace project to use (detected from the current directory by default)"
+    )]
+    pub project: Option<String>,
+
     #[command(subcommand)]
     pub commands: Option<Commands>,
 }
 
-#[derive(Subcommand, Debug)]
+#[derive(Subcommand, Debug, Clone)]
 pub enum Commands {
     #[clap(about = "Create a new item in a module (module/item_type/name)")]
     Create {
-        #[clap(help = "Path in format: module/item_type/name")]
+        #[clap(help = "Path in format: [category:]module/item_type/name")]
         path: String,
+
+        #[clap(
+            long = "set",
+            value_name = "KEY=VALUE",
+            help = "Set a template variable declared by the item (repeatable)"
+        )]
+        set: Vec<String>,
+
+        #[clap(
+            long = "no-tests",
+            help = "Skip extra files conditioned on tests (sets the `tests` variable to false)"
+        )]
+        no_tests: bool,
+
+        #[clap(
+            long = "category",
+            value_name = "CATEGORY",
+            help = "Category to create the item in, when its item type exists in several (same as a 'category:' path prefix)"
+        )]
+        category: Option<String>,
+
+        #[clap(
+            long = "on-conflict",
+            value_enum,
+            help = "What to do with files that already exist (overrides preferences)"
+        )]
+        on_conflict: Option<ConflictPolicy>,
+
+        #[clap(
+            long = "output",
+            value_enum,
+            help = "How to print the result (overrides preferences)"
+        )]
+        output: Option<OutputFormat>,
+
+        #[clap(long = "open", help = "Open the generated files in your editor")]
+        open: bool,
+
+        #[clap(
+            long = "no-hooks",
+            help = "Don't run the config's pre_generate and post_generate hooks"
+        )]
+        no_hooks: bool,
+
+        #[clap(long = "stage", help = "git add the created files")]
+        stage: bool,
+
+        #[clap(
+            long = "commit",
+            help = "Commit the created files with the configured git.commit_message"
+        )]
+        commit: bool,
+
+        #[clap(
+            long = "allow-dirty",
+            help = "Create even if files about to be written have uncommitted changes (overrides git.require_clean)"
+        )]
+        allow_dirty: bool,
+    },
+
+    #[clap(about = "Print an item's rendered templates without writing any files")]
+    Render {
+        #[clap(
+            help = "Item as [category:]module/item_type[/name] or item_type, or a path to a .hbs template"
+        )]
+        target: String,
+
+        #[clap(
+            long = "name",
+            help = "Name to render with, unless the target already ends with it"
+        )]
+        name: Option<String>,
+
+        #[clap(
+            long = "set",
+            value_name = "KEY=VALUE",
+            help = "Set a template variable declared by the item (repeatable)"
+        )]
+        set: Vec<String>,
+
+        #[clap(
+            long = "no-tests",
+            help = "Skip extra files conditioned on tests (sets the `tests` variable to false)"
+        )]
+        no_tests: bool,
+
+        #[clap(
+            long = "category",
+            value_name = "CATEGORY",
+            help = "Category of the item, when its item type exists in several"
+        )]
+        category: Option<String>,
+
+        #[clap(long = "highlight", help = "Color the output by the file's syntax")]
+        highlight: bool,
+
+        #[clap(
+            long = "watch",
+            help = "Render again whenever the config or a template changes"
+        )]
+        watch: bool,
+    },
+
+    #[clap(about = "Render template fixtures and compare them with their snapshots")]
+    TestTemplates {
+        #[clap(
+            long = "update",
+            help = "Record the rendered output as the new snapshots instead of failing"
+        )]
+        update: bool,
     },
 
     #[clap(about = "List available modules and items from config")]
@@ -36,8 +155,91 @@ pub enum Commands {
     Init {
         #[clap(short = 'p', long = "preset", help = "Preset configuration to use")]
         preset: Option<String>,
+
+        #[clap(
+            long = "wizard",
+            conflicts_with = "preset",
+            help = "Build the config step by step instead of copying a preset"
+        )]
+        wizard: bool,
+    },
+
+    #[clap(about = "Scaffold or remove module folders")]
+    Module {
+        #[command(subcommand)]
+        action: ModuleAction,
     },
 
+    #[clap(about = "Check the config, templates and project folders for problems")]
+    Doctor,
+
     #[clap(about = "Run interactive mode for guided project setup")]
     Interactive,
+
+    #[clap(about = "Inspect creator configuration")]
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
+    #[clap(about = "Print a shell completion script (source it from your shell's startup file)")]
+    Completions {
+        #[clap(value_enum, help = "Shell to generate the completion script for")]
+        shell: CompletionShell,
+    },
+
+    /// Complete a partial `create` path, one candidate per line (used by the completion scripts)
+    #[command(name = "__complete", hide = true)]
+    Complete {
+        #[arg(allow_hyphen_values = true, default_value = "")]
+        current: String,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigAction {
+    #[clap(about = "Show the effective configuration and where each value comes from")]
+    Show,
+
+    #[clap(about = "Rewrite the config file in the current schema version")]
+    Migrate {
+        #[clap(long = "dry-run", help = "Print the changes without writing the file")]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ModuleAction {
+    #[clap(about = "Create a module's folder with a folder for each of its item types")]
+    Scaffold {
+        #[clap(help = "Module name, optionally as category:name")]
+        name: String,
+
+        #[clap(
+            long = "category",
+            value_name = "CATEGORY",
+            help = "Category to create the module in, when several allow modules"
+        )]
+        category: Option<String>,
+    },
+
+    #[clap(about = "Delete a module's folder and everything in it")]
+    Remove {
+        #[clap(help = "Module name, optionally as category:name")]
+        name: String,
+
+        #[clap(
+            long = "category",
+            value_name = "CATEGORY",
+            help = "Category of the module, when several have a module with that name"
+        )]
+        category: Option<String>,
+
+        #[clap(
+            short = 'y',
+            long = "yes",
+            help = "Remove without asking for confirmation"
+        )]
+        yes: bool,
+    },
 }
@@ -0,0 +1,376 @@
+use anyhow::{anyhow, Result};
+use std::path::{Path, PathBuf};
+
+use crate::config::{Category, Item, ProjectConfig};
+use crate::file_utils::to_kebab_case;
+use crate::suggest::did_you_mean;
+
+/// An item located in the category tree from a create path
+#[derive(Debug)]
+pub struct ResolvedItem<'a> {
+    /// Top-level category name
+    pub category_name: String,
+    /// Folders between the source directory and the item type folder
+    pub directories: Vec<String>,
+    /// Categories walked from the top-level category to the one holding the item
+    pub categories: Vec<&'a Category>,
+    /// Names given to dynamic segments (modules), outermost first
+    pub modules: Vec<String>,
+    pub item_type: String,
+    pub item: &'a Item,
+    pub item_name: String,
+}
+
+impl<'a> ResolvedItem<'a> {
+    /// Folder that receives the item's files: source_dir/directories.../item_type
+    pub fn item_dir(&self, source_dir: &Path) -> PathBuf {
+        self.directories
+            .iter()
+            .fold(source_dir.to_path_buf(), |path, dir| path.join(dir))
+            .join(&self.item_type)
+    }
+
+    /// Innermost category declaring a file name case, falling back to the top-level category
+    pub fn file_name_case_category(&self) -> Option<&'a Category> {
+        self.categories
+            .iter()
+            .rev()
+            .find(|category| category.file_name_case.is_some())
+            .or(self.categories.first())
+            .copied()
+    }
+
+    /// Name of the innermost module, or the category name for static paths
+    pub fn module_name(&self) -> &str {
+        self.modules
+            .last()
+            .map(String::as_str)
+            .unwrap_or(&self.category_name)
+    }
+}
+
+/// Resolve `category/[subcategory|module]*/item_type/name` against the category tree.
+/// Errors name the segment that failed to match and what was expected there.
+pub fn resolve_tree_path<'a>(config: &'a ProjectConfig, path: &str) -> Result<ResolvedItem<'a>> {
+    let segments: Vec<&str> = path.split('/').collect();
+    let category_name = segments[0];
+    let mut category = config.get_category(category_name).ok_or_else(|| {
+        let categories = config.get_categories();
+        anyhow!(
+            "Segment 1 '{}' of path '{}' is not a category.\n{}💡 Available categories: {}",
+            category_name,
+            path,
+            did_you_mean(category_name, categories.iter().map(String::as_str)),
+            categories.join(", ")
+        )
+    })?;
+
+    let mut directories = vec![category_name.to_string()];
+    let mut categories = vec![category];
+    let mut modules = Vec::new();
+    let mut index = 1;
+
+    loop {
+        let remaining = &segments[index..];
+        let location = segments[..index].join("/");
+
+        // Two segments left that are not an item type, but would lead deeper into the tree
+        let leads_deeper = remaining.len() == 2
+            && category.get_item(remaining[0]).is_none()
+            && (category.get_subcategory(remaining[0]).is_some()
+                || category.supports_dynamic_children());
+
+        if remaining.len() < 2 || leads_deeper {
+            return Err(anyhow!(
+                "Path '{}' ends inside '{}' before reaching item_type/name. 💡 Expected one of: {}",
+                path,
+                location,
+                describe_expected(category).join(", ")
+            ));
+        }
+
+        let segment = remaining[0];
+        let failed_segment = |position: usize, value: &str, category: &Category| {
+            let names = category
+                .get_item_names()
+                .into_iter()
+                .chain(category.get_subcategory_names());
+            anyhow!(
+                "Segment {} '{}' of path '{}' does not match anything in '{}'.\n{}💡 Expected one of: {}",
+                position,
+                value,
+                path,
+                location,
+                did_you_mean(value, names.collect::<Vec<_>>().iter().map(String::as_str)),
+                describe_expected(category).join(", ")
+            )
+        };
+
+        if remaining.len() == 2 {
+            if let Some(item) = category.get_item(segment) {
+                return Ok(ResolvedItem {
+                    category_name: category_name.to_string(),
+                    directories,
+                    categories,
+                    modules,
+                    item_type: segment.to_string(),
+                    item,
+                    item_name: remaining[1].to_string(),
+                });
+            }
+            return Err(failed_segment(index + 1, segment, category));
+        }
+
+        if let Some(subcategory) = category.get_subcategory(segment) {
+            directories.push(segment.to_string());
+            categories.push(subcategory);
+            category = subcategory;
+            index += 1;
+            continue;
+        }
+
+        if !category.supports_dynamic_children() {
+            return Err(failed_segment(index + 1, segment, category));
+        }
+
+        // Dynamic segment: the module name
+        modules.push(segment.to_string());
+        directories.push(to_kebab_case(segment));
+
+        if let Some(module_structure) = category.get_module_structure() {
+            categories.push(module_structure);
+            category = module_structure;
+            index += 1;
+            continue;
+        }
+
+        let default_structure = category.get_default_structure();
+        let item_type = remaining[1];
+        let item = default_structure.and_then(|items| items.get(item_type));
+        return match (item, remaining.len()) {
+            (Some(item), 3) => Ok(ResolvedItem {
+                category_name: category_name.to_string(),
+                directories,
+                categories,
+                modules,
+                item_type: item_type.to_string(),
+                item,
+                item_name: remaining[2].to_string(),
+            }),
+            (Some(_), _) => Err(anyhow!(
+                "Path '{}' has extra segments after '{}/{}/{}'. 💡 Expected: item_type/name",
+                path,
+                location,
+                segment,
+                item_type
+            )),
+            (None, _) => {
+                let item_types: Vec<&str> = default_structure
+                    .map(|items| items.keys().map(String::as_str).collect())
+                    .unwrap_or_default();
+                Err(anyhow!(
+                    "Segment {} '{}' of path '{}' is not an item type of module '{}' in '{}'.\n{}💡 Expected one of: {}",
+                    index + 2,
+                    item_type,
+                    path,
+                    segment,
+                    location,
+                    did_you_mean(item_type, item_types.iter().copied()),
+                    item_types.join(", ")
+                ))
+            }
+        };
+    }
+}
+
+/// Split an explicit `category:` prefix off a create path
+pub fn split_category_prefix(path: &str) -> (Option<&str>, &str) {
+    match path.split_once(':') {
+        Some((category, rest)) => (Some(category), rest),
+        None => (None, path),
+    }
+}
+
+/// Describe what may follow a category: item types, nested categories and dynamic modules
+fn describe_expected(category: &Category) -> Vec<String> {
+    let mut expected: Vec<String> = category
+        .get_item_names()
+        .into_iter()
+        .map(|item| format!("{} (item type)", item))
+        .collect();
+
+    expected.extend(
+        category
+            .get_subcategory_names()
+            .into_iter()
+            .map(|name| format!("{}/ (category)", name)),
+    );
+
+    if category.supports_dynamic_children() {
+        expected.push("<module>/ (dynamic)".to_string());
+    }
+
+    expected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested_config() -> ProjectConfig {
+        let config_json = r#"
+        {
+            "project": {
+                "name": "test-project",
+                "version": "1.0",
+                "structure": {
+                    "features": {
+                        "allow_dynamic_children": true,
+                        "module_structure": {
+                            "allow_dynamic_children": true,
+                            "module_structure": {
+                                "file_name_case": "pascal",
+                                "categories": {
+                                    "ui": {
+                                        "children": {
+                                            "components": {
+                                                "template": "templates/components.hbs",
+                                                "file_extension": "tsx"
+                                            }
+                                        }
+                                    }
+                                },
+                                "children": {
+                                    "hooks": {
+                                        "template": "templates/hooks.hbs",
+                                        "file_extension": "ts"
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "modules": {
+                        "allow_dynamic_children": true,
+                        "default_structure": {
+                            "services": {
+                                "template": "templates/default.hbs",
+                                "file_extension": "ts"
+                            }
+                        }
+                    },
+                    "pages": {
+                        "children": {
+                            "dashboard": {
+                                "template": "templates/components.hbs",
+                                "file_extension": "tsx"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        "#;
+
+        let config: ProjectConfig = serde_json::from_str(config_json).unwrap();
+        config.validate().unwrap();
+        config
+    }
+
+    #[test]
+    fn test_resolve_deeply_nested_path() {
+        let config = nested_config();
+        let resolved = resolve_tree_path(
+            &config,
+            "features/billing/Invoice_History/ui/components/invoice-list",
+        )
+        .unwrap();
+
+        assert_eq!(resolved.category_name, "features");
+        assert_eq!(
+            resolved.directories,
+            vec!["features", "billing", "invoice-history", "ui"]
+        );
+        assert_eq!(resolved.modules, vec!["billing", "Invoice_History"]);
+        assert_eq!(resolved.module_name(), "Invoice_History");
+        assert_eq!(resolved.item_type, "components");
+        assert_eq!(resolved.item_name, "invoice-list");
+        assert_eq!(
+            resolved.item_dir(Path::new("src")),
+            PathBuf::from("src/features/billing/invoice-history/ui/components")
+        );
+        assert!(resolved
+            .file_name_case_category()
+            .unwrap()
+            .file_name_case
+            .is_some());
+    }
+
+    #[test]
+    fn test_resolve_flat_dynamic_and_static_paths() {
+        let config = nested_config();
+
+        let resolved = resolve_tree_path(&config, "modules/cats/services/cat-api").unwrap();
+        assert_eq!(resolved.directories, vec!["modules", "cats"]);
+        assert_eq!(resolved.item_type, "services");
+
+        let resolved = resolve_tree_path(&config, "pages/dashboard/main").unwrap();
+        assert_eq!(resolved.directories, vec!["pages"]);
+        assert!(resolved.modules.is_empty());
+        assert_eq!(resolved.module_name(), "pages");
+    }
+
+    #[test]
+    fn test_split_category_prefix() {
+        assert_eq!(
+            split_category_prefix("modules:cats/hooks/x"),
+            (Some("modules"), "cats/hooks/x")
+        );
+        assert_eq!(
+            split_category_prefix("cats/hooks/x"),
+            (None, "cats/hooks/x")
+        );
+    }
+
+    #[test]
+    fn test_resolve_reports_failing_segment() {
+        let config = nested_config();
+
+        let cases = vec![
+            (
+                "features/billing/invoices/uii/components/list",
+                "Segment 4 'uii'",
+            ),
+            (
+                "features/billing/invoices/ui/buttons/list",
+                "Segment 5 'buttons'",
+            ),
+            (
+                "modules/cats/repositories/cat-api",
+                "Segment 3 'repositories'",
+            ),
+            ("modules/cats/services/api/extra", "extra segments"),
+            ("pages/settings/main", "Segment 2 'settings'"),
+            ("unknown/cats/services/x", "Segment 1 'unknown'"),
+            (
+                "features/billing/invoices",
+                "before reaching item_type/name",
+            ),
+        ];
+
+        for (path, expected) in cases {
+            let error_msg = resolve_tree_path(&config, path).unwrap_err().to_string();
+            assert!(
+                error_msg.contains(expected),
+                "Unexpected error for '{}': {}",
+                path,
+                error_msg
+            );
+        }
+
+        let error_msg = resolve_tree_path(&config, "features/billing/invoices/uii/components/x")
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("hooks (item type)"));
+        assert!(error_msg.contains("ui/ (category)"));
+    }
+}
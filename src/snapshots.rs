@@ -0,0 +1,267 @@
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How to render a template for one snapshot test, read from `<case>.json`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Fixture {
+    /// Item name passed to the template, e.g. `cat-list`
+    pub name: String,
+    /// Item the template belongs to, as accepted by `creator render` (`components`,
+    /// `modules:components`, `cats/components`). Its naming rules and variables apply.
+    #[serde(default)]
+    pub item: Option<String>,
+    #[serde(default)]
+    pub variables: Map<String, Value>,
+}
+
+/// A fixture and where its expected output is stored
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixtureCase {
+    /// File stem of the fixture, e.g. `with-props` for `with-props.json`
+    pub case: String,
+    pub fixture: Fixture,
+    pub snapshot_path: PathBuf,
+}
+
+/// Result of comparing a rendered template with its snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    /// The output differs; holds a line diff of expected against actual
+    Failed(String),
+    /// No snapshot has been recorded yet
+    Missing,
+    /// The snapshot was written or rewritten in update mode
+    Updated,
+}
+
+/// Directory holding a template's fixtures: `components.hbs` -> `components.fixtures`
+pub fn fixtures_dir(template: &Path) -> PathBuf {
+    template.with_extension("fixtures")
+}
+
+/// Read every fixture of a template, sorted by case name. Templates without
+/// a fixtures directory have none.
+pub fn load_fixtures(template: &Path) -> Result<Vec<FixtureCase>> {
+    let dir = fixtures_dir(template);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(&dir)
+        .map_err(|e| anyhow!("Failed to read fixtures in {}: {}", dir.display(), e))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("json"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let contents = fs::read_to_string(&path)
+                .map_err(|e| anyhow!("Failed to read fixture {}: {}", path.display(), e))?;
+            let fixture: Fixture = serde_json::from_str(&contents)
+                .map_err(|e| anyhow!("Invalid fixture {}: {}", path.display(), e))?;
+            let case = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default()
+                .to_string();
+
+            Ok(FixtureCase {
+                case,
+                fixture,
+                snapshot_path: path.with_extension("snap"),
+            })
+        })
+        .collect()
+}
+
+/// Compare rendered output with a fixture's snapshot. In update mode a missing or
+/// different snapshot is (re)written instead of failing.
+pub fn check_snapshot(case: &FixtureCase, actual: &str, update: bool) -> Result<Outcome> {
+    let expected = match fs::read_to_string(&case.snapshot_path) {
+        Ok(expected) => Some(expected),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => {
+            return Err(anyhow!(
+                "Failed to read snapshot {}: {}",
+                case.snapshot_path.display(),
+                e
+            ))
+        }
+    };
+
+    match expected {
+        Some(expected) if expected == actual => Ok(Outcome::Passed),
+        _ if update => {
+            fs::write(&case.snapshot_path, actual).map_err(|e| {
+                anyhow!(
+                    "Failed to write snapshot {}: {}",
+                    case.snapshot_path.display(),
+                    e
+                )
+            })?;
+            Ok(Outcome::Updated)
+        }
+        Some(expected) => Ok(Outcome::Failed(line_diff(&expected, actual))),
+        None => Ok(Outcome::Missing),
+    }
+}
+
+/// Fixture variables as `--set` style strings, for validation against an item's declarations
+pub fn variables_as_assignments(variables: &Map<String, Value>) -> HashMap<String, String> {
+    variables
+        .iter()
+        .map(|(key, value)| {
+            let value = match value {
+                Value::String(value) => value.clone(),
+                other => other.to_string(),
+            };
+            (key.clone(), value)
+        })
+        .collect()
+}
+
+/// Every `*.hbs` file under `dir`, sorted
+pub fn find_templates(dir: &Path) -> Vec<PathBuf> {
+    let mut templates = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return templates;
+    };
+
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            templates.extend(find_templates(&path));
+        } else if path.extension().and_then(|ext| ext.to_str()) == Some("hbs") {
+            templates.push(path);
+        }
+    }
+    templates
+}
+
+/// Line diff of two texts: unchanged lines are indented, removed lines start with
+/// `-` and added lines with `+`
+pub fn line_diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // lengths[i][j] is the longest common subsequence of old[i..] and new[j..]
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            diff.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+
+    if expected.ends_with('\n') != actual.ends_with('\n') {
+        diff.push("(trailing newline differs)".to_string());
+    }
+    diff.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_fixtures() {
+        let temp_dir = TempDir::new().unwrap();
+        let template = temp_dir.path().join("components.hbs");
+        fs::write(&template, "{{templateName}}").unwrap();
+        assert!(load_fixtures(&template).unwrap().is_empty());
+
+        let dir = temp_dir.path().join("components.fixtures");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("with-props.json"),
+            r#"{ "name": "cat-list", "item": "modules:components", "variables": { "withProps": true } }"#,
+        )
+        .unwrap();
+        fs::write(dir.join("basic.json"), r#"{ "name": "cat-list" }"#).unwrap();
+        fs::write(dir.join("basic.snap"), "CatList").unwrap();
+
+        let cases = load_fixtures(&template).unwrap();
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[0].case, "basic");
+        assert_eq!(cases[0].snapshot_path, dir.join("basic.snap"));
+        assert_eq!(cases[1].fixture.item.as_deref(), Some("modules:components"));
+        assert_eq!(cases[1].fixture.variables["withProps"], Value::Bool(true));
+
+        fs::write(dir.join("typo.json"), r#"{ "name": "x", "vars": {} }"#).unwrap();
+        let error_msg = load_fixtures(&template).unwrap_err().to_string();
+        assert!(error_msg.contains("typo.json"));
+        assert!(error_msg.contains("unknown field `vars`"));
+    }
+
+    #[test]
+    fn test_check_snapshot() {
+        let temp_dir = TempDir::new().unwrap();
+        let case = FixtureCase {
+            case: "basic".to_string(),
+            fixture: Fixture::default(),
+            snapshot_path: temp_dir.path().join("basic.snap"),
+        };
+
+        assert_eq!(
+            check_snapshot(&case, "a\n", false).unwrap(),
+            Outcome::Missing
+        );
+        assert_eq!(
+            check_snapshot(&case, "a\n", true).unwrap(),
+            Outcome::Updated
+        );
+        assert_eq!(
+            check_snapshot(&case, "a\n", false).unwrap(),
+            Outcome::Passed
+        );
+        assert_eq!(
+            check_snapshot(&case, "b\n", false).unwrap(),
+            Outcome::Failed("- a\n+ b".to_string())
+        );
+        assert_eq!(
+            check_snapshot(&case, "b\n", true).unwrap(),
+            Outcome::Updated
+        );
+        assert_eq!(fs::read_to_string(&case.snapshot_path).unwrap(), "b\n");
+    }
+
+    #[test]
+    fn test_line_diff() {
+        assert_eq!(
+            line_diff("import a\nfn one\nend", "import a\nfn two\nend\n"),
+            "  import a\n- fn one\n+ fn two\n  end\n(trailing newline differs)"
+        );
+        assert_eq!(line_diff("same", "same"), "  same");
+    }
+}
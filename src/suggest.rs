@@ -0,0 +1,84 @@
+/// Edit distance between two strings, counting insertions, deletions, substitutions
+/// and swaps of adjacent characters as one edit each. Case is ignored.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+
+    // rows[i][j] is the distance between the first i chars of a and the first j of b
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
+
+/// Find the candidate closest to `input`, if it's close enough to be a likely typo.
+/// Ties go to the earliest candidate.
+pub fn closest<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    // Allow one edit per three characters, and at least one
+    let max_distance = (input.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != input)
+        .map(|candidate| (edit_distance(input, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// "💡 Did you mean 'x'?" for the closest candidate, followed by a newline, or nothing
+pub fn did_you_mean<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> String {
+    closest(input, candidates)
+        .map(|candidate| format!("💡 Did you mean '{}'?\n", candidate))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("components", "components"), 0);
+        assert_eq!(edit_distance("componets", "components"), 1);
+        assert_eq!(edit_distance("hoosk", "hooks"), 1);
+        assert_eq!(edit_distance("Pages", "pages"), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("services", "screens"), 5);
+    }
+
+    #[test]
+    fn test_closest() {
+        let candidates = ["components", "hooks", "services", "screens"];
+        assert_eq!(
+            closest("componets", candidates.iter().copied()),
+            Some("components")
+        );
+        assert_eq!(closest("hoks", candidates.iter().copied()), Some("hooks"));
+        assert_eq!(closest("widgets", candidates.iter().copied()), None);
+        assert_eq!(closest("x", candidates.iter().copied()), None);
+        assert_eq!(
+            did_you_mean("screen", candidates.iter().copied()),
+            "💡 Did you mean 'screens'?\n"
+        );
+        assert_eq!(did_you_mean("widgets", candidates.iter().copied()), "");
+    }
+}
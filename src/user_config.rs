@@ -0,0 +1,138 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Preferences, ProjectConfig};
+
+/// User config file inside the user config directory
+pub const USER_CONFIG_FILE: &str = "config.json";
+
+/// Personal template overrides inside the user config directory
+pub const USER_TEMPLATES_DIR: &str = "templates";
+
+/// Personal settings, merged under the project config
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserConfig {
+    #[serde(default)]
+    pub preferences: Preferences,
+}
+
+/// Get the user config directory: `$XDG_CONFIG_HOME/creator`, falling back to `~/.config/creator`
+pub fn user_config_dir() -> Option<PathBuf> {
+    config_home().map(|(_, dir)| dir.join("creator"))
+}
+
+/// Name of the environment variable the user config directory is derived from
+pub fn user_config_dir_variable() -> Option<&'static str> {
+    config_home().map(|(variable, _)| variable)
+}
+
+fn config_home() -> Option<(&'static str, PathBuf)> {
+    let xdg_config_home = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .map(|dir| ("XDG_CONFIG_HOME", dir));
+
+    xdg_config_home
+        .or_else(|| env::var_os("HOME").map(|home| ("HOME", PathBuf::from(home).join(".config"))))
+}
+
+impl UserConfig {
+    /// Load the user config from `dir`; a missing file means no personal settings
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(USER_CONFIG_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Failed to read user config {}: {}", path.display(), e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| anyhow!("Failed to parse user config {}: {}", path.display(), e))
+    }
+
+    /// Merge these settings under a project config: values set by the project win,
+    /// and templates in `dir/templates` override the project's templates
+    pub fn merge_under(&self, config: &mut ProjectConfig, dir: &Path) {
+        config.preferences = Some(config.preferences().or(self.preferences.clone()));
+
+        let templates_dir = dir.join(USER_TEMPLATES_DIR);
+        if templates_dir.is_dir() {
+            config.template_overrides = Some(templates_dir);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ConflictPolicy, OutputFormat};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_and_merge_under_project() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(
+            UserConfig::load(temp_dir.path()).unwrap(),
+            UserConfig::default()
+        );
+
+        fs::write(
+            temp_dir.path().join(USER_CONFIG_FILE),
+            r#"{ "preferences": { "on_conflict": "skip", "output": "json", "editor": "code" } }"#,
+        )
+        .unwrap();
+        fs::create_dir_all(temp_dir.path().join("templates/react")).unwrap();
+        fs::write(temp_dir.path().join("templates/components.hbs"), "mine").unwrap();
+        fs::write(temp_dir.path().join("templates/react/page.hbs"), "mine").unwrap();
+
+        let user_config = UserConfig::load(temp_dir.path()).unwrap();
+        let mut config = ProjectConfig {
+            preferences: Some(Preferences {
+                on_conflict: Some(ConflictPolicy::Error),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        user_config.merge_under(&mut config, temp_dir.path());
+
+        let preferences = config.preferences();
+        assert_eq!(preferences.on_conflict, Some(ConflictPolicy::Error));
+        assert_eq!(preferences.output, Some(OutputFormat::Json));
+        assert_eq!(preferences.editor.as_deref(), Some("code"));
+        // Templates outside a templates_dir are overridden by file name
+        assert_eq!(
+            config.resolve_template_path("templates/components.hbs"),
+            temp_dir.path().join("templates/components.hbs")
+        );
+        assert_eq!(
+            config.resolve_template_path("templates/hooks.hbs"),
+            PathBuf::from("templates/hooks.hbs")
+        );
+
+        // Templates in the templates_dir are overridden by their path inside it
+        let shared = TempDir::new().unwrap();
+        fs::create_dir_all(shared.path().join("react")).unwrap();
+        fs::write(shared.path().join("react/page.hbs"), "shared").unwrap();
+        config.project.templates_dir = Some(shared.path().to_string_lossy().to_string());
+        assert_eq!(
+            config.resolve_template_path("react/page.hbs"),
+            temp_dir.path().join("templates/react/page.hbs")
+        );
+    }
+
+    #[test]
+    fn test_invalid_user_config() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(USER_CONFIG_FILE),
+            r#"{ "preferences": { "on_conflict": "ask" } }"#,
+        )
+        .unwrap();
+
+        let error_msg = UserConfig::load(temp_dir.path()).unwrap_err().to_string();
+        assert!(error_msg.contains("Failed to parse user config"));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::config::NameValidation;
+use crate::file_utils::{is_valid_name, to_kebab_case};
+
+/// Validate a user-provided name (`kind` is "module" or "item") against the built-in
+/// character check and every configured rule set. The error names the rule that failed.
+pub fn validate_name(
+    kind: &str,
+    name: &str,
+    rules: &[&NameValidation],
+    existing_modules: &[String],
+) -> Result<(), String> {
+    let fail = |reason: String, rule: &str| {
+        Err(format!(
+            "Invalid {} name '{}': {} (rule: {})",
+            kind, name, reason, rule
+        ))
+    };
+
+    if name.trim().is_empty() {
+        return fail("name cannot be empty".to_string(), "required");
+    }
+
+    if !is_valid_name(name) {
+        return fail(
+            "use only letters, numbers, hyphens, and underscores".to_string(),
+            "characters",
+        );
+    }
+
+    let length = name.chars().count();
+
+    for rules in rules {
+        if let Some(min) = rules.min_length {
+            if length < min {
+                return fail(
+                    format!("must be at least {} characters long", min),
+                    "min_length",
+                );
+            }
+        }
+
+        if let Some(max) = rules.max_length {
+            if length > max {
+                return fail(
+                    format!("must be at most {} characters long", max),
+                    "max_length",
+                );
+            }
+        }
+
+        if let Some(prefix) = &rules.required_prefix {
+            if !name.starts_with(prefix.as_str()) {
+                return fail(format!("must start with '{}'", prefix), "required_prefix");
+            }
+        }
+
+        if let Some(reserved) = rules
+            .reserved
+            .iter()
+            .find(|word| word.eq_ignore_ascii_case(name))
+        {
+            return fail(format!("'{}' is a reserved word", reserved), "reserved");
+        }
+
+        let regex = rules.pattern_regex().map_err(|e| {
+            format!(
+                "Invalid validation pattern '{}': {}",
+                rules.pattern.as_deref().unwrap_or_default(),
+                e
+            )
+        })?;
+        if let (Some(pattern), Some(regex)) = (&rules.pattern, regex) {
+            if !regex.is_match(name) {
+                return fail(format!("must match pattern '{}'", pattern), "pattern");
+            }
+        }
+
+        if rules.forbid_existing_modules.unwrap_or(false) {
+            let normalized = to_kebab_case(name);
+            if existing_modules.contains(&normalized) {
+                return fail(
+                    format!("collides with existing module '{}'", normalized),
+                    "forbid_existing_modules",
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_rules() {
+        assert!(validate_name("item", "cat-list", &[], &[]).is_ok());
+
+        let error = validate_name("item", "", &[], &[]).unwrap_err();
+        assert!(error.contains("Invalid item name"));
+        assert!(error.contains("rule: required"));
+
+        let error = validate_name("module", "user profile", &[], &[]).unwrap_err();
+        assert!(error.contains("Invalid module name 'user profile'"));
+        assert!(error.contains("rule: characters"));
+    }
+
+    #[test]
+    fn test_configured_rules_report_failed_rule() {
+        let rules = NameValidation {
+            pattern: Some("^[a-z][a-z0-9-]*$".to_string()),
+            reserved: vec!["index".to_string(), "default".to_string()],
+            min_length: Some(3),
+            max_length: Some(12),
+            required_prefix: None,
+            forbid_existing_modules: Some(true),
+            ..Default::default()
+        };
+        let existing_modules = vec!["cats".to_string()];
+
+        let cases = vec![
+            ("ab", "min_length"),
+            ("a-very-long-name", "max_length"),
+            ("Index", "reserved"),
+            ("Cats", "pattern"),
+            ("cats", "forbid_existing_modules"),
+        ];
+
+        for (name, rule) in cases {
+            let error = validate_name("item", name, &[&rules], &existing_modules).unwrap_err();
+            assert!(
+                error.contains(&format!("rule: {}", rule)),
+                "Expected rule '{}' to fail for '{}', got: {}",
+                rule,
+                name,
+                error
+            );
+        }
+
+        assert!(validate_name("item", "cat-list", &[&rules], &existing_modules).is_ok());
+
+        // The pattern is compiled once and reused by later checks
+        let compiled = rules.pattern_regex().unwrap().unwrap() as *const regex::Regex;
+        assert!(std::ptr::eq(
+            rules.pattern_regex().unwrap().unwrap(),
+            compiled
+        ));
+    }
+
+    #[test]
+    fn test_rules_are_combined() {
+        let project_rules = NameValidation {
+            max_length: Some(20),
+            ..Default::default()
+        };
+        let hook_rules = NameValidation {
+            required_prefix: Some("use".to_string()),
+            ..Default::default()
+        };
+
+        let error =
+            validate_name("item", "cat-list", &[&project_rules, &hook_rules], &[]).unwrap_err();
+        assert!(error.contains("must start with 'use'"));
+        assert!(validate_name("item", "use-cat-list", &[&project_rules, &hook_rules], &[]).is_ok());
+    }
+}
@@ -0,0 +1,406 @@
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+use inquire::{Confirm, Select, Text};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use crate::config::{Variable, VariableType};
+
+/// Parse `--set key=value` assignments
+pub fn parse_assignments(assignments: &[String]) -> Result<HashMap<String, String>> {
+    let mut values = HashMap::new();
+
+    for assignment in assignments {
+        let (key, value) = assignment.split_once('=').ok_or_else(|| {
+            anyhow!(
+                "Invalid variable assignment '{}'. Expected: key=value",
+                assignment
+            )
+        })?;
+
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(anyhow!(
+                "Invalid variable assignment '{}'. Variable name cannot be empty",
+                assignment
+            ));
+        }
+
+        values.insert(key.to_string(), value.to_string());
+    }
+
+    Ok(values)
+}
+
+/// Build the template variables for an item from its declarations and the provided values.
+/// Undeclared values are rejected; missing values fall back to the declared default,
+/// then to `false`, an empty string or the first enum option.
+pub fn resolve_variables(
+    definitions: Option<&IndexMap<String, Variable>>,
+    provided: &HashMap<String, String>,
+) -> Result<Map<String, Value>> {
+    let empty = IndexMap::new();
+    let definitions = definitions.unwrap_or(&empty);
+
+    let mut unknown: Vec<&String> = provided
+        .keys()
+        .filter(|key| !definitions.contains_key(*key))
+        .collect();
+    if !unknown.is_empty() {
+        unknown.sort();
+        let available: Vec<&String> = definitions.keys().collect();
+        return Err(anyhow!(
+            "Unknown variable(s): {}. Available: {}",
+            unknown
+                .iter()
+                .map(|k| k.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available
+                    .iter()
+                    .map(|k| k.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        ));
+    }
+
+    let mut variables = Map::new();
+    for (name, definition) in definitions {
+        let value = match provided.get(name) {
+            Some(raw) => parse_value(name, definition, raw)?,
+            None => default_value(definition),
+        };
+        variables.insert(name.clone(), value);
+    }
+
+    Ok(variables)
+}
+
+/// Convert a raw string into the variable's declared type
+pub fn parse_value(name: &str, definition: &Variable, raw: &str) -> Result<Value> {
+    match definition.var_type {
+        VariableType::Bool => match raw.trim().to_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok(Value::Bool(true)),
+            "false" | "no" | "0" => Ok(Value::Bool(false)),
+            _ => Err(anyhow!(
+                "Invalid value '{}' for bool variable '{}'. Use true or false",
+                raw,
+                name
+            )),
+        },
+        VariableType::String => Ok(Value::String(raw.to_string())),
+        VariableType::Enum => {
+            if definition.options.iter().any(|option| option == raw) {
+                Ok(Value::String(raw.to_string()))
+            } else {
+                Err(anyhow!(
+                    "Invalid value '{}' for variable '{}'. Expected one of: {}",
+                    raw,
+                    name,
+                    definition.options.join(", ")
+                ))
+            }
+        }
+    }
+}
+
+/// Default value for a variable when nothing was provided
+pub fn default_value(definition: &Variable) -> Value {
+    if let Some(default) = &definition.default {
+        return default.clone();
+    }
+
+    match definition.var_type {
+        VariableType::Bool => Value::Bool(false),
+        VariableType::String => Value::String(String::new()),
+        VariableType::Enum => definition
+            .options
+            .first()
+            .map(|option| Value::String(option.clone()))
+            .unwrap_or(Value::Null),
+    }
+}
+
+/// Prompt for every declared variable and return the answers as `key=value` assignments
+pub fn prompt_variables(definitions: Option<&IndexMap<String, Variable>>) -> Result<Vec<String>> {
+    let Some(definitions) = definitions else {
+        return Ok(Vec::new());
+    };
+
+    // Prompt in declaration order
+    let mut assignments = Vec::new();
+    for (name, definition) in definitions {
+        let message = match &definition.description {
+            Some(description) => format!("{} ({}):", name, description),
+            None => format!("{}:", name),
+        };
+        let default = default_value(definition);
+
+        let value = match definition.var_type {
+            VariableType::Bool => Confirm::new(&message)
+                .with_default(default.as_bool().unwrap_or(false))
+                .prompt()
+                .map_err(|_| anyhow!("Failed to read variable '{}'", name))?
+                .to_string(),
+            VariableType::String => Text::new(&message)
+                .with_default(default.as_str().unwrap_or_default())
+                .prompt()
+                .map_err(|_| anyhow!("Failed to read variable '{}'", name))?,
+            VariableType::Enum => {
+                let starting_cursor = default
+                    .as_str()
+                    .and_then(|d| definition.options.iter().position(|o| o == d))
+                    .unwrap_or(0);
+                Select::new(&message, definition.options.clone())
+                    .with_starting_cursor(starting_cursor)
+                    .prompt()
+                    .map_err(|_| anyhow!("Failed to read variable '{}'", name))?
+            }
+        };
+
+        assignments.push(format!("{}={}", name, value));
+    }
+
+    Ok(assignments)
+}
+
+/// Built-in render context variable: false when `--no-tests` is passed
+pub const TESTS_VARIABLE: &str = "tests";
+
+/// A single comparison inside a `when` condition
+enum Condition<'a> {
+    Truthy(&'a str),
+    Falsy(&'a str),
+    Equals(&'a str, &'a str),
+    NotEquals(&'a str, &'a str),
+}
+
+/// Parse a condition into `||`-separated groups of `&&`-separated terms.
+/// Terms are `name`, `!name`, `name == value` or `name != value`.
+fn parse_condition(expression: &str) -> Result<Vec<Vec<Condition<'_>>>> {
+    let invalid = || anyhow!("Invalid condition '{}'", expression);
+
+    expression
+        .split("||")
+        .map(|group| {
+            group
+                .split("&&")
+                .map(|term| {
+                    let term = term.trim();
+                    let condition = if let Some((name, value)) = term.split_once("!=") {
+                        Condition::NotEquals(name.trim(), value.trim())
+                    } else if let Some((name, value)) = term.split_once("==") {
+                        Condition::Equals(name.trim(), value.trim())
+                    } else if let Some(name) = term.strip_prefix('!') {
+                        Condition::Falsy(name.trim())
+                    } else {
+                        Condition::Truthy(term)
+                    };
+
+                    let name = match condition {
+                        Condition::Truthy(name)
+                        | Condition::Falsy(name)
+                        | Condition::Equals(name, _)
+                        | Condition::NotEquals(name, _) => name,
+                    };
+                    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                        return Err(invalid());
+                    }
+
+                    Ok(condition)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Names of the variables referenced by a condition
+pub fn condition_variables(expression: &str) -> Result<Vec<String>> {
+    Ok(parse_condition(expression)?
+        .iter()
+        .flatten()
+        .map(|condition| match condition {
+            Condition::Truthy(name)
+            | Condition::Falsy(name)
+            | Condition::Equals(name, _)
+            | Condition::NotEquals(name, _) => name.to_string(),
+        })
+        .collect())
+}
+
+/// Evaluate a `when` condition against the render context
+pub fn evaluate_condition(expression: &str, context: &Map<String, Value>) -> Result<bool> {
+    let is_truthy = |name: &str| match context.get(name) {
+        None | Some(Value::Null) => false,
+        Some(Value::Bool(value)) => *value,
+        Some(Value::String(value)) => !value.is_empty(),
+        Some(Value::Number(value)) => value.as_f64() != Some(0.0),
+        Some(Value::Array(value)) => !value.is_empty(),
+        Some(Value::Object(_)) => true,
+    };
+    let as_text = |name: &str| match context.get(name) {
+        Some(Value::String(value)) => value.clone(),
+        Some(Value::Null) | None => String::new(),
+        Some(value) => value.to_string(),
+    };
+
+    Ok(parse_condition(expression)?.iter().any(|group| {
+        group.iter().all(|condition| match condition {
+            Condition::Truthy(name) => is_truthy(name),
+            Condition::Falsy(name) => !is_truthy(name),
+            Condition::Equals(name, value) => as_text(name) == *value,
+            Condition::NotEquals(name, value) => as_text(name) != *value,
+        })
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definitions() -> IndexMap<String, Variable> {
+        let mut definitions = IndexMap::new();
+        definitions.insert(
+            "withProps".to_string(),
+            Variable {
+                var_type: VariableType::Bool,
+                default: Some(Value::Bool(true)),
+                description: Some("Generate a props interface".to_string()),
+                options: Vec::new(),
+            },
+        );
+        definitions.insert(
+            "routeName".to_string(),
+            Variable {
+                var_type: VariableType::String,
+                default: None,
+                description: None,
+                options: Vec::new(),
+            },
+        );
+        definitions.insert(
+            "stateLib".to_string(),
+            Variable {
+                var_type: VariableType::Enum,
+                default: None,
+                description: None,
+                options: vec!["redux".to_string(), "zustand".to_string()],
+            },
+        );
+        definitions
+    }
+
+    #[test]
+    fn test_evaluate_condition() {
+        let mut context = Map::new();
+        context.insert("withStyles".to_string(), Value::Bool(true));
+        context.insert(TESTS_VARIABLE.to_string(), Value::Bool(false));
+        context.insert("stateLib".to_string(), Value::String("redux".to_string()));
+        context.insert("routeName".to_string(), Value::String(String::new()));
+
+        let cases = vec![
+            ("withStyles", true),
+            ("tests", false),
+            ("!tests", true),
+            ("routeName", false),
+            ("missing", false),
+            ("stateLib == redux", true),
+            ("stateLib != redux", false),
+            ("withStyles && stateLib == zustand", false),
+            ("tests || stateLib == redux", true),
+            (
+                "tests || !withStyles || stateLib==redux && withStyles",
+                true,
+            ),
+        ];
+
+        for (expression, expected) in cases {
+            assert_eq!(
+                evaluate_condition(expression, &context).unwrap(),
+                expected,
+                "Unexpected result for '{}'",
+                expression
+            );
+        }
+    }
+
+    #[test]
+    fn test_condition_variables() {
+        assert_eq!(
+            condition_variables("withStyles && !tests || stateLib == redux").unwrap(),
+            vec!["withStyles", "tests", "stateLib"]
+        );
+
+        for invalid in ["", "with-styles", "== redux", "a && "] {
+            assert!(
+                condition_variables(invalid).is_err(),
+                "Expected '{}' to be invalid",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_assignments() {
+        let values =
+            parse_assignments(&["withProps=false".to_string(), "routeName=a=b".to_string()])
+                .unwrap();
+        assert_eq!(values["withProps"], "false");
+        assert_eq!(values["routeName"], "a=b");
+
+        assert!(parse_assignments(&["withProps".to_string()]).is_err());
+        assert!(parse_assignments(&["=value".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_variables_with_defaults() {
+        let variables = resolve_variables(Some(&definitions()), &HashMap::new()).unwrap();
+        assert_eq!(variables["withProps"], Value::Bool(true));
+        assert_eq!(variables["routeName"], Value::String(String::new()));
+        assert_eq!(variables["stateLib"], Value::String("redux".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_variables_with_provided_values() {
+        let provided = parse_assignments(&[
+            "withProps=no".to_string(),
+            "routeName=CatDetails".to_string(),
+            "stateLib=zustand".to_string(),
+        ])
+        .unwrap();
+
+        let variables = resolve_variables(Some(&definitions()), &provided).unwrap();
+        assert_eq!(variables["withProps"], Value::Bool(false));
+        assert_eq!(
+            variables["routeName"],
+            Value::String("CatDetails".to_string())
+        );
+        assert_eq!(variables["stateLib"], Value::String("zustand".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_variables_rejects_invalid_values() {
+        let provided = parse_assignments(&["stateLib=mobx".to_string()]).unwrap();
+        let error_msg = resolve_variables(Some(&definitions()), &provided)
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Expected one of: redux, zustand"));
+
+        let provided = parse_assignments(&["withProps=maybe".to_string()]).unwrap();
+        assert!(resolve_variables(Some(&definitions()), &provided).is_err());
+
+        let provided = parse_assignments(&["unknown=1".to_string()]).unwrap();
+        let error_msg = resolve_variables(Some(&definitions()), &provided)
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Unknown variable(s): unknown"));
+        assert!(error_msg.contains("Available: withProps, routeName, stateLib"));
+
+        let error_msg = resolve_variables(None, &provided).unwrap_err().to_string();
+        assert!(error_msg.contains("Available: none"));
+    }
+}
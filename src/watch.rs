@@ -0,0 +1,178 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::config::ProjectConfig;
+use crate::extends::load_layers;
+
+/// How often watched files are checked for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The watched files and their modification times; any difference means something changed
+pub type Stamp = Vec<(PathBuf, Option<SystemTime>)>;
+
+/// Files that affect rendering: the config file and the files it extends, every
+/// template the config uses, and everything in the shared templates directory.
+/// `project_config` is the last config that loaded, if any.
+pub fn watched_files(config_path: &Path, project_config: Option<&ProjectConfig>) -> Vec<PathBuf> {
+    let mut files = vec![config_path.to_path_buf()];
+    if let Ok(layers) = load_layers(config_path) {
+        files.extend(
+            layers
+                .iter()
+                .map(|layer| PathBuf::from(&layer.source))
+                .filter(|path| path.is_file()),
+        );
+    }
+
+    if let Some(project_config) = project_config {
+        files.extend(project_config.declared_templates());
+        if let Some(dir) = project_config.templates_dir() {
+            files.extend(files_under(&dir));
+        }
+    }
+
+    let mut unique = Vec::new();
+    for file in files {
+        if !unique.contains(&file) {
+            unique.push(file);
+        }
+    }
+    unique
+}
+
+/// Modification time of each file, `None` for files that don't exist (yet)
+pub fn stamp(files: &[PathBuf]) -> Stamp {
+    files
+        .iter()
+        .map(|file| {
+            let modified = fs::metadata(file).and_then(|m| m.modified()).ok();
+            (file.clone(), modified)
+        })
+        .collect()
+}
+
+/// Call `on_change` now and again whenever one of the watched files changes. The
+/// files are listed again after each change, since the config decides what to watch.
+/// Runs until the process is interrupted.
+pub fn watch(mut files: impl FnMut() -> Vec<PathBuf>, mut on_change: impl FnMut()) -> ! {
+    let mut last: Option<Stamp> = None;
+    loop {
+        let watched = files();
+        let current = stamp(&watched);
+        if last.as_ref() != Some(&current) {
+            on_change();
+            // A config change can change what's watched. Otherwise keep the stamp from
+            // before `on_change`, so edits made while it ran are picked up next time.
+            let now_watched = files();
+            last = Some(if now_watched == watched {
+                current
+            } else {
+                stamp(&now_watched)
+            });
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Every file under `dir`, sorted
+fn files_under(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return files;
+    };
+
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            files.extend(files_under(&path));
+        } else {
+            files.push(path);
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_watched_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("templates/partials")).unwrap();
+        fs::write(root.join("templates/components.hbs"), "").unwrap();
+        fs::write(root.join("templates/partials/header.hbs"), "").unwrap();
+        fs::write(
+            root.join("base.json"),
+            r#"{ "project": { "name": "app" } }"#,
+        )
+        .unwrap();
+
+        let config_path = root.join("config.json");
+        let config = format!(
+            r#"{{
+                "extends": "base.json",
+                "project": {{
+                    "version": "1.0",
+                    "templates_dir": "{templates}",
+                    "structure": {{
+                        "modules": {{
+                            "allow_dynamic_children": true,
+                            "default_structure": {{
+                                "components": {{ "template": "{component}", "file_extension": "tsx" }}
+                            }}
+                        }}
+                    }}
+                }}
+            }}"#,
+            templates = root.join("templates").display(),
+            component = root.join("templates/components.hbs").display(),
+        );
+        fs::write(&config_path, config).unwrap();
+
+        // Without a loaded config only the config files are known
+        assert_eq!(
+            watched_files(&config_path, None),
+            vec![config_path.clone(), root.join("base.json")]
+        );
+
+        let project_config = ProjectConfig::load_and_validate(&config_path).unwrap();
+        assert_eq!(
+            watched_files(&config_path, Some(&project_config)),
+            vec![
+                config_path.clone(),
+                root.join("base.json"),
+                root.join("templates/components.hbs"),
+                root.join("templates/partials/header.hbs"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stamp_changes_with_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("components.hbs");
+        let files = vec![file.clone()];
+
+        let missing = stamp(&files);
+        assert_eq!(missing, vec![(file.clone(), None)]);
+
+        fs::write(&file, "a").unwrap();
+        let created = stamp(&files);
+        assert_ne!(created, missing);
+
+        let earlier = SystemTime::now() - Duration::from_secs(60);
+        fs::File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(earlier)
+            .unwrap();
+        assert_ne!(stamp(&files), created);
+    }
+}
@@ -0,0 +1,379 @@
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+use inquire::{validator::Validation, Confirm, Select, Text};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Category, Item, ProjectConfig, ProjectInfo};
+use crate::migrate::CURRENT_SCHEMA_VERSION;
+
+/// Contents of the template files created for new item types
+const STUB_TEMPLATE: &str = "export function {{templateName}}() {}\n";
+
+const DYNAMIC_KIND: &str = "Dynamic: modules created on demand (modules/cats/components/...)";
+const STATIC_KIND: &str = "Static: a fixed set of item types (pages/screens/...)";
+
+/// A category as answered in the wizard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryAnswers {
+    pub name: String,
+    pub description: Option<String>,
+    /// Whether modules are created inside the category on demand
+    pub dynamic: bool,
+    pub items: Vec<ItemAnswers>,
+}
+
+/// An item type as answered in the wizard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemAnswers {
+    pub name: String,
+    pub file_extension: String,
+    pub template: String,
+}
+
+/// Build `creator init --wizard`'s config from the answers
+pub fn build_config(project_name: &str, categories: Vec<CategoryAnswers>) -> ProjectConfig {
+    let structure = categories
+        .into_iter()
+        .map(|category| {
+            let items: IndexMap<String, Item> = category
+                .items
+                .into_iter()
+                .map(|item| {
+                    (
+                        item.name,
+                        Item {
+                            template: item.template,
+                            file_extension: item.file_extension,
+                            ..Default::default()
+                        },
+                    )
+                })
+                .collect();
+
+            let config = if category.dynamic {
+                Category {
+                    description: category.description,
+                    allow_dynamic_children: Some(true),
+                    default_structure: Some(items),
+                    ..Default::default()
+                }
+            } else {
+                Category {
+                    description: category.description,
+                    children: Some(items),
+                    ..Default::default()
+                }
+            };
+            (category.name, config)
+        })
+        .collect();
+
+    ProjectConfig {
+        schema_version: Some(CURRENT_SCHEMA_VERSION),
+        project: ProjectInfo {
+            name: project_name.to_string(),
+            version: "1.0".to_string(),
+            structure,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Validate the config, create a stub for every template that doesn't exist yet and
+/// write the config. Template paths are relative to the config file's directory.
+/// Returns the stub templates created.
+pub fn write_config(config: &ProjectConfig, config_path: &Path) -> Result<Vec<PathBuf>> {
+    config
+        .validate()
+        .map_err(|e| anyhow!("The generated config is invalid: {}", e))?;
+
+    let base_dir = config_path.parent().unwrap_or(Path::new(""));
+    let mut stubs = Vec::new();
+    for category in config.project.structure.values() {
+        let items = category
+            .children
+            .iter()
+            .chain(category.default_structure.iter())
+            .flat_map(|items| items.values());
+        for item in items {
+            let path = base_dir.join(&item.template);
+            if path.exists() || stubs.contains(&path) {
+                continue;
+            }
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|e| {
+                    anyhow!("Failed to create directory {}: {}", parent.display(), e)
+                })?;
+            }
+            fs::write(&path, STUB_TEMPLATE)
+                .map_err(|e| anyhow!("Failed to write template {}: {}", path.display(), e))?;
+            stubs.push(path);
+        }
+    }
+
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| anyhow!("Failed to serialize the config: {}", e))?;
+    fs::write(config_path, json + "\n").map_err(|e| {
+        anyhow!(
+            "Failed to write config file {}: {}",
+            config_path.display(),
+            e
+        )
+    })?;
+
+    Ok(stubs)
+}
+
+/// Ask before replacing an existing config file
+pub fn confirm_overwrite(config_path: &Path) -> Result<bool> {
+    Confirm::new(&format!(
+        "{} already exists. Overwrite it?",
+        config_path.display()
+    ))
+    .with_default(false)
+    .prompt()
+    .map_err(|_| anyhow!("Failed to confirm overwrite"))
+}
+
+/// Build a config step by step with prompts and write it to `config_path`
+pub fn run_wizard(config_path: &Path) -> Result<()> {
+    println!("🧙 Let's build your Creator config step by step.");
+    println!();
+
+    if config_path.exists() && !confirm_overwrite(config_path)? {
+        return Err(anyhow!(
+            "{} already exists.\n💡 Pass another path with --config",
+            config_path.display()
+        ));
+    }
+
+    let default_name = std::env::current_dir()
+        .ok()
+        .and_then(|dir| dir.file_name()?.to_str().map(String::from))
+        .unwrap_or_else(|| "my-app".to_string());
+    let project_name = Text::new("Project name:")
+        .with_default(&default_name)
+        .with_validator(|input: &str| {
+            Ok(if input.trim().is_empty() {
+                Validation::Invalid("Project name cannot be empty".into())
+            } else {
+                Validation::Valid
+            })
+        })
+        .prompt()
+        .map_err(|_| anyhow!("Failed to get project name"))?;
+
+    let mut categories: Vec<CategoryAnswers> = Vec::new();
+    loop {
+        println!();
+        let taken: Vec<String> = categories.iter().map(|c| c.name.clone()).collect();
+        categories.push(prompt_category(&taken)?);
+
+        let another = Confirm::new("Add another category?")
+            .with_default(false)
+            .prompt()
+            .map_err(|_| anyhow!("Failed to confirm"))?;
+        if !another {
+            break;
+        }
+    }
+
+    let config = build_config(project_name.trim(), categories);
+    let stubs = write_config(&config, config_path)?;
+
+    println!();
+    println!("✅ Created config file: {}", config_path.display());
+    for stub in &stubs {
+        println!("   📄 {}", stub.display());
+    }
+    println!("🎯 Edit the templates, then run 'creator list' to see your structure.");
+
+    Ok(())
+}
+
+fn prompt_category(taken: &[String]) -> Result<CategoryAnswers> {
+    let name = prompt_key("Category name:", "e.g., modules, pages, shared", taken)?;
+    let description = Text::new("Description (optional):")
+        .prompt()
+        .map_err(|_| anyhow!("Failed to get description"))?;
+    let kind = Select::new(
+        &format!("What does '{}' contain?", name),
+        vec![DYNAMIC_KIND, STATIC_KIND],
+    )
+    .prompt()
+    .map_err(|_| anyhow!("Failed to select category kind"))?;
+
+    let mut items: Vec<ItemAnswers> = Vec::new();
+    loop {
+        let taken: Vec<String> = items.iter().map(|i| i.name.clone()).collect();
+        items.push(prompt_item(&name, &taken)?);
+
+        let another = Confirm::new(&format!("Add another item type to '{}'?", name))
+            .with_default(true)
+            .prompt()
+            .map_err(|_| anyhow!("Failed to confirm"))?;
+        if !another {
+            break;
+        }
+    }
+
+    Ok(CategoryAnswers {
+        name,
+        description: Some(description.trim().to_string()).filter(|d| !d.is_empty()),
+        dynamic: kind == DYNAMIC_KIND,
+        items,
+    })
+}
+
+fn prompt_item(category_name: &str, taken: &[String]) -> Result<ItemAnswers> {
+    let name = prompt_key(
+        &format!("Item type in '{}':", category_name),
+        "e.g., components, hooks, services",
+        taken,
+    )?;
+    let file_extension = Text::new("File extension:")
+        .with_default("ts")
+        .with_validator(|input: &str| {
+            Ok(if input.trim().trim_start_matches('.').is_empty() {
+                Validation::Invalid("File extension cannot be empty".into())
+            } else {
+                Validation::Valid
+            })
+        })
+        .prompt()
+        .map_err(|_| anyhow!("Failed to get file extension"))?;
+    let template = Text::new("Template:")
+        .with_default(&format!("templates/{}.hbs", name))
+        .prompt()
+        .map_err(|_| anyhow!("Failed to get template path"))?;
+
+    Ok(ItemAnswers {
+        name,
+        file_extension: file_extension.trim().trim_start_matches('.').to_string(),
+        template: template.trim().to_string(),
+    })
+}
+
+/// Prompt for a category or item type name, unique among `taken`
+fn prompt_key(message: &str, placeholder: &str, taken: &[String]) -> Result<String> {
+    let taken = taken.to_vec();
+    Text::new(message)
+        .with_placeholder(placeholder)
+        .with_validator(move |input: &str| {
+            let input = input.trim();
+            Ok(if input.is_empty() {
+                Validation::Invalid("Name cannot be empty".into())
+            } else if input.contains(['/', ':', ' ']) {
+                Validation::Invalid("Name cannot contain '/', ':' or spaces".into())
+            } else if taken.iter().any(|name| name == input) {
+                Validation::Invalid(format!("'{}' is already used", input).into())
+            } else {
+                Validation::Valid
+            })
+        })
+        .prompt()
+        .map(|name| name.trim().to_string())
+        .map_err(|_| anyhow!("Failed to get name"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn answers() -> Vec<CategoryAnswers> {
+        vec![
+            CategoryAnswers {
+                name: "modules".to_string(),
+                description: Some("Feature modules".to_string()),
+                dynamic: true,
+                items: vec![
+                    ItemAnswers {
+                        name: "components".to_string(),
+                        file_extension: "tsx".to_string(),
+                        template: "templates/components.hbs".to_string(),
+                    },
+                    ItemAnswers {
+                        name: "hooks".to_string(),
+                        file_extension: "ts".to_string(),
+                        template: "templates/hooks.hbs".to_string(),
+                    },
+                ],
+            },
+            CategoryAnswers {
+                name: "pages".to_string(),
+                description: None,
+                dynamic: false,
+                items: vec![ItemAnswers {
+                    name: "screens".to_string(),
+                    file_extension: "tsx".to_string(),
+                    template: "templates/components.hbs".to_string(),
+                }],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_build_config() {
+        let config = build_config("my-app", answers());
+        assert_eq!(config.schema_version, Some(CURRENT_SCHEMA_VERSION));
+        assert_eq!(config.get_categories(), vec!["modules", "pages"]);
+
+        let modules = config.get_category("modules").unwrap();
+        assert!(modules.supports_dynamic_children());
+        assert_eq!(
+            modules
+                .get_default_structure()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["components", "hooks"]
+        );
+
+        let pages = config.get_category("pages").unwrap();
+        assert!(!pages.supports_dynamic_children());
+        assert_eq!(pages.get_item_names(), vec!["screens"]);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_write_config_creates_stub_templates() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        fs::create_dir_all(temp_dir.path().join("templates")).unwrap();
+        fs::write(temp_dir.path().join("templates/hooks.hbs"), "custom").unwrap();
+
+        let stubs = write_config(&build_config("my-app", answers()), &config_path).unwrap();
+
+        // Existing templates are kept, shared ones are created once
+        assert_eq!(
+            stubs,
+            vec![temp_dir.path().join("templates/components.hbs")]
+        );
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("templates/hooks.hbs")).unwrap(),
+            "custom"
+        );
+
+        let written = ProjectConfig::load_and_validate(&config_path).unwrap();
+        assert_eq!(written.project.name, "my-app");
+        assert_eq!(written.get_categories(), vec!["modules", "pages"]);
+    }
+
+    #[test]
+    fn test_write_config_rejects_invalid_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        let mut categories = answers();
+        categories[1].items[0].file_extension = String::new();
+
+        let error_msg = write_config(&build_config("my-app", categories), &config_path)
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("The generated config is invalid"));
+        assert!(!config_path.exists());
+        assert!(!temp_dir.path().join("templates").exists());
+    }
+}
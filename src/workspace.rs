@@ -0,0 +1,352 @@
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Category, ProjectConfig, ProjectInfo};
+
+/// File name looked up in the current directory and its parents
+pub const WORKSPACE_FILE: &str = "creator-workspace.json";
+
+/// Source directory used when a workspace project doesn't set one
+const DEFAULT_SOURCE_DIR: &str = "src";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceConfig {
+    pub workspace: WorkspaceInfo,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkspaceInfo {
+    pub name: String,
+    pub version: String,
+    pub projects: Vec<WorkspaceProject>,
+}
+
+/// A project of the workspace: either a path to its own config or an inline structure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkspaceProject {
+    pub name: String,
+    /// Project folder, relative to the workspace file
+    pub root: String,
+    /// Source folder, relative to the project root (defaults to `src`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_dir: Option<String>,
+    /// Project config file, relative to the workspace file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structure: Option<IndexMap<String, Category>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates_dir: Option<String>,
+}
+
+impl WorkspaceConfig {
+    /// Check whether a config file declares a workspace rather than a single project
+    pub fn is_workspace_file(path: &Path) -> bool {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+            .is_some_and(|value| value.get("workspace").is_some())
+    }
+
+    /// Load workspace configuration from file and validate it
+    pub fn load_and_validate(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read workspace file: {}", e))?;
+        let workspace: WorkspaceConfig = serde_json::from_str(&contents)
+            .map_err(|e| anyhow!("Failed to parse workspace JSON: {}", e))?;
+
+        workspace.validate()?;
+
+        Ok(workspace)
+    }
+
+    /// Validate the workspace: projects exist, have unique names and one structure source
+    pub fn validate(&self) -> Result<()> {
+        if self.workspace.projects.is_empty() {
+            return Err(anyhow!("Workspace must list at least one project"));
+        }
+
+        for (index, project) in self.workspace.projects.iter().enumerate() {
+            if project.name.is_empty() {
+                return Err(anyhow!("Workspace project #{} has no name", index + 1));
+            }
+
+            if self.workspace.projects[..index]
+                .iter()
+                .any(|other| other.name == project.name)
+            {
+                return Err(anyhow!(
+                    "Workspace project '{}' is declared more than once",
+                    project.name
+                ));
+            }
+
+            if project.root.is_empty() {
+                return Err(anyhow!("Workspace project '{}' has no root", project.name));
+            }
+
+            match (&project.config, &project.structure) {
+                (Some(_), Some(_)) => {
+                    return Err(anyhow!(
+                        "Workspace project '{}' cannot have both config and structure",
+                        project.name
+                    ));
+                }
+                (None, None) => {
+                    return Err(anyhow!(
+                        "Workspace project '{}' needs either a config file or an inline structure",
+                        project.name
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get a project by name
+    pub fn find_project(&self, name: &str) -> Result<&WorkspaceProject> {
+        self.workspace
+            .projects
+            .iter()
+            .find(|project| project.name == name)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Project '{}' not found in workspace '{}'. 💡 Available projects: {}",
+                    name,
+                    self.workspace.name,
+                    self.project_names().join(", ")
+                )
+            })
+    }
+
+    /// Find the project whose root contains `cwd`, preferring the deepest root
+    pub fn detect_project(&self, workspace_dir: &Path, cwd: &Path) -> Option<&WorkspaceProject> {
+        let cwd = cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf());
+
+        self.workspace
+            .projects
+            .iter()
+            .filter_map(|project| {
+                let root = project.root_dir(workspace_dir);
+                let root = root.canonicalize().unwrap_or(root);
+                cwd.starts_with(&root)
+                    .then_some((root.components().count(), project))
+            })
+            .max_by_key(|(depth, _)| *depth)
+            .map(|(_, project)| project)
+    }
+
+    /// Get project names in declaration order
+    pub fn project_names(&self) -> Vec<String> {
+        self.workspace
+            .projects
+            .iter()
+            .map(|project| project.name.clone())
+            .collect()
+    }
+
+    /// Load and validate the configuration of a project
+    pub fn load_project_config(&self, workspace_dir: &Path, name: &str) -> Result<ProjectConfig> {
+        let project = self.find_project(name)?;
+
+        let mut config = match (&project.config, &project.structure) {
+            (Some(config_path), _) => {
+                ProjectConfig::load_and_validate(&workspace_dir.join(config_path))
+                    .map_err(|e| anyhow!("Project '{}': {}", project.name, e))?
+            }
+            (None, structure) => {
+                let config = ProjectConfig {
+                    project: ProjectInfo {
+                        name: project.name.clone(),
+                        version: self.workspace.version.clone(),
+                        structure: structure.clone().unwrap_or_default(),
+                        templates_dir: project.templates_dir.clone(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                };
+                config
+                    .validate()
+                    .map_err(|e| anyhow!("Project '{}': {}", project.name, e))?;
+                config
+            }
+        };
+
+        config.base_dir = Some(project.root_dir(workspace_dir));
+        Ok(config)
+    }
+}
+
+impl WorkspaceProject {
+    /// Get the project root directory
+    pub fn root_dir(&self, workspace_dir: &Path) -> PathBuf {
+        workspace_dir.join(&self.root)
+    }
+
+    /// Get the source directory items are created in
+    pub fn source_dir(&self, workspace_dir: &Path) -> PathBuf {
+        self.root_dir(workspace_dir)
+            .join(self.source_dir.as_deref().unwrap_or(DEFAULT_SOURCE_DIR))
+    }
+}
+
+/// Look for a workspace file in `start` and its parent directories
+pub fn find_workspace_file(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(WORKSPACE_FILE))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_workspace(temp_dir: &Path) -> PathBuf {
+        fs::create_dir_all(temp_dir.join("apps/web/src")).unwrap();
+        fs::create_dir_all(temp_dir.join("packages/ui/lib/components")).unwrap();
+        fs::write(
+            temp_dir.join("apps/web/creator.json"),
+            r#"
+            {
+                "project": {
+                    "name": "web",
+                    "version": "1.0",
+                    "structure": {
+                        "pages": {
+                            "children": {
+                                "dashboard": { "template": "templates/page.hbs", "file_extension": "tsx" }
+                            }
+                        }
+                    }
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let workspace_path = temp_dir.join(WORKSPACE_FILE);
+        fs::write(
+            &workspace_path,
+            r#"
+            {
+                "workspace": {
+                    "name": "acme",
+                    "version": "2.0",
+                    "projects": [
+                        { "name": "web", "root": "apps/web", "config": "apps/web/creator.json" },
+                        {
+                            "name": "ui",
+                            "root": "packages/ui",
+                            "source_dir": "lib",
+                            "structure": {
+                                "components": {
+                                    "children": {
+                                        "buttons": { "template": "templates/button.hbs", "file_extension": "tsx" }
+                                    }
+                                }
+                            }
+                        }
+                    ]
+                }
+            }
+            "#,
+        )
+        .unwrap();
+        workspace_path
+    }
+
+    #[test]
+    fn test_load_workspace_projects() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_path = write_workspace(temp_dir.path());
+        assert!(WorkspaceConfig::is_workspace_file(&workspace_path));
+
+        let workspace = WorkspaceConfig::load_and_validate(&workspace_path).unwrap();
+        assert_eq!(workspace.project_names(), vec!["web", "ui"]);
+
+        let web = workspace
+            .load_project_config(temp_dir.path(), "web")
+            .unwrap();
+        assert_eq!(web.get_categories(), vec!["pages"]);
+        assert_eq!(web.base_dir, Some(temp_dir.path().join("apps/web")));
+
+        let ui = workspace
+            .load_project_config(temp_dir.path(), "ui")
+            .unwrap();
+        assert_eq!(ui.project.name, "ui");
+        assert_eq!(ui.project.version, "2.0");
+        assert_eq!(
+            workspace
+                .find_project("ui")
+                .unwrap()
+                .source_dir(temp_dir.path()),
+            temp_dir.path().join("packages/ui/lib")
+        );
+
+        let error_msg = workspace
+            .load_project_config(temp_dir.path(), "mobile")
+            .unwrap_err()
+            .to_string();
+        assert!(error_msg.contains("Project 'mobile' not found"));
+        assert!(error_msg.contains("web, ui"));
+    }
+
+    #[test]
+    fn test_detect_project_by_cwd() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_path = write_workspace(temp_dir.path());
+        let workspace = WorkspaceConfig::load_and_validate(&workspace_path).unwrap();
+
+        let detected = workspace
+            .detect_project(
+                temp_dir.path(),
+                &temp_dir.path().join("packages/ui/lib/components"),
+            )
+            .unwrap();
+        assert_eq!(detected.name, "ui");
+        assert!(workspace
+            .detect_project(temp_dir.path(), temp_dir.path())
+            .is_none());
+
+        assert_eq!(
+            find_workspace_file(&temp_dir.path().join("apps/web/src")),
+            Some(workspace_path)
+        );
+    }
+
+    #[test]
+    fn test_invalid_workspace() {
+        let cases = vec![
+            (
+                r#"{ "workspace": { "name": "w", "version": "1", "projects": [] } }"#,
+                "at least one project",
+            ),
+            (
+                r#"{ "workspace": { "name": "w", "version": "1", "projects": [
+                    { "name": "a", "root": "a", "config": "a.json" },
+                    { "name": "a", "root": "b", "config": "b.json" }
+                ] } }"#,
+                "declared more than once",
+            ),
+            (
+                r#"{ "workspace": { "name": "w", "version": "1", "projects": [
+                    { "name": "a", "root": "a" }
+                ] } }"#,
+                "needs either a config file or an inline structure",
+            ),
+        ];
+
+        for (json, expected) in cases {
+            let workspace: WorkspaceConfig = serde_json::from_str(json).unwrap();
+            let error_msg = workspace.validate().unwrap_err().to_string();
+            assert!(error_msg.contains(expected), "{}", error_msg);
+        }
+    }
+}
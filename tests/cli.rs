@@ -0,0 +1,112 @@
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use tempfile::TempDir;
+
+/// Run the binary in `dir` without a user config and return stdout and stderr
+fn run(dir: &Path, args: &[&str]) -> (String, String) {
+    let output = command(dir, args).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "creator failed: {}", stderr);
+    (stdout, stderr)
+}
+
+/// The binary run in `dir` without a user config or a terminal, so prompts fail
+fn command(dir: &Path, args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_creator"));
+    command
+        .current_dir(dir)
+        .args(args)
+        .env("XDG_CONFIG_HOME", dir.join(".no-user-config"))
+        .stdin(Stdio::null());
+    command
+}
+
+#[test]
+fn test_json_output_is_the_only_stdout() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::create_dir_all(dir.join("templates")).unwrap();
+    fs::write(
+        dir.join("templates/default.hbs"),
+        "export const {{templateName}} = 1;",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("config.json"),
+        r#"{ "project": { "name": "cli", "version": "1.0", "structure": {
+            "modules": { "allow_dynamic_children": true, "default_structure": {
+                "services": { "template": "templates/default.hbs", "file_extension": "ts" }
+            } }
+        } } }"#,
+    )
+    .unwrap();
+
+    let (stdout, stderr) = run(
+        dir,
+        &["create", "cats/services/cat-api", "--output", "json"],
+    );
+
+    let summary: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(summary["name"], "cat-api");
+    assert_eq!(
+        summary["created"][0],
+        "src/modules/cats/services/cat-api.ts"
+    );
+    assert!(stderr.contains("📋 Found config: config.json"));
+    assert!(stderr.contains("📁 Found source directory: src"));
+}
+
+#[test]
+fn test_json_output_in_a_workspace() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let project_dir = dir.join("packages/ui");
+    fs::create_dir_all(project_dir.join("templates")).unwrap();
+    fs::write(
+        project_dir.join("templates/button.hbs"),
+        "export function {{templateName}}() {}",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("creator-workspace.json"),
+        r#"{ "workspace": { "name": "acme", "version": "1.0", "projects": [
+            { "name": "ui", "root": "packages/ui", "structure": {
+                "components": { "children": {
+                    "buttons": { "template": "templates/button.hbs", "file_extension": "tsx" }
+                } }
+            } }
+        ] } }"#,
+    )
+    .unwrap();
+
+    let (stdout, stderr) = run(
+        &project_dir,
+        &["create", "components/buttons/primary", "--output", "json"],
+    );
+
+    let summary: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(summary["name"], "primary");
+    assert!(stderr.contains("📋 Found workspace"));
+    assert!(stderr.contains("📦 Using project: ui"));
+}
+
+#[test]
+fn test_interactive_offers_init_for_a_broken_config() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(dir.join("config.json"), "{ not json").unwrap();
+
+    let output = command(dir, &["interactive"]).output().unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    // The config error is reported, then the init prompt is reached (and fails
+    // without a terminal) instead of stopping at the config error
+    assert!(stderr.contains("⚠️  Could not load config.json"));
+    assert!(stderr.contains("Failed to confirm initializing a new config"));
+    assert!(!stderr.contains("Configuration error"));
+}